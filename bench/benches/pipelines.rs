@@ -0,0 +1,43 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use fusion_bench::{fusion, futures, iter};
+
+const N: u64 = 1 << 16;
+
+macro_rules! compare {
+    ($c:expr, $name:ident $(, $arg:expr)*) => {{
+        let mut group = $c.benchmark_group(stringify!($name));
+        group.bench_function("fusion", |b| b.iter(|| fusion::$name($(black_box($arg)),*)));
+        group.bench_function("futures", |b| b.iter(|| futures::$name($(black_box($arg)),*)));
+        group.bench_function("std", |b| b.iter(|| iter::$name($(black_box($arg)),*)));
+        group.finish();
+    }};
+}
+
+fn pipelines(c: &mut Criterion) {
+    compare!(c, step_filter_fold, N);
+    compare!(c, sparse_filter_count, N);
+    compare!(c, flat_map_sum, N);
+    compare!(c, map_async_sum, N);
+}
+
+fn parallel(c: &mut Criterion) {
+    let data: Vec<u64> = (0..N * 16).collect();
+    for threads in [1, 4] {
+        let mut group = c.benchmark_group(format!("split_fold/{threads}"));
+        group.bench_function("fusion", |b| {
+            b.iter(|| fusion::split_fold(black_box(&data), threads))
+        });
+        group.bench_function("futures", |b| {
+            b.iter(|| futures::split_fold(black_box(&data), threads))
+        });
+        group.bench_function("std", |b| {
+            b.iter(|| iter::split_fold(black_box(&data), threads))
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, pipelines, parallel);
+criterion_main!(benches);
@@ -0,0 +1,214 @@
+//! The same pipelines written with `fusion-core`, `futures-util` streams and `std` iterators,
+//! shared by the benchmarks and checked against each other in tests.
+
+/// How many items consumers handle before yielding back to the executor.
+pub const YIELD_STEP: usize = 1024;
+
+pub mod fusion {
+    use fusion_core::prelude::*;
+    use fusion_parallel::IndexedParallelStream;
+
+    use super::YIELD_STEP;
+
+    pub fn step_filter_fold(n: u64) -> u64 {
+        spin_on::spin_on(async {
+            (0..n)
+                .into_fusion()
+                .step_by(2)
+                .map(|i| i + 1)
+                .filter(|i| *i > 512)
+                .fold(0, |acc, i| acc + i)
+                .yield_by(YIELD_STEP)
+                .await
+        })
+    }
+
+    pub fn sparse_filter_count(n: u64) -> usize {
+        spin_on::spin_on(async {
+            (0..n)
+                .into_fusion()
+                .filter(|i| i % 128 == 0)
+                .count()
+                .yield_by(YIELD_STEP)
+                .await
+        })
+    }
+
+    pub fn flat_map_sum(n: u64) -> u64 {
+        spin_on::spin_on(async {
+            (0..n)
+                .into_fusion()
+                .flat_map(|i| (0..i % 16).into_fusion())
+                .fold(0, |acc, i| acc + i)
+                .yield_by(YIELD_STEP)
+                .await
+        })
+    }
+
+    pub fn map_async_sum(n: u64) -> u64 {
+        spin_on::spin_on(async {
+            (0..n)
+                .into_fusion()
+                .map_async(|i| async move { i * 3 })
+                .fold(0, |acc, i| acc + i)
+                .yield_by(YIELD_STEP)
+                .await
+        })
+    }
+
+    pub fn split_fold(data: &[u64], threads: usize) -> u64 {
+        std::thread::scope(|s| {
+            let handles: Vec<_> = data
+                .fusion_split(threads)
+                .map(|stream| {
+                    s.spawn(move || {
+                        spin_on::spin_on(async {
+                            stream
+                                .map(|i| i.wrapping_mul(3))
+                                .fold(0u64, |acc, i| acc.wrapping_add(i))
+                                .yield_by(YIELD_STEP)
+                                .await
+                        })
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .fold(0u64, |acc, h| acc.wrapping_add(h.join().unwrap()))
+        })
+    }
+}
+
+pub mod futures {
+    use futures_util::{
+        future,
+        stream::{self, StreamExt},
+    };
+
+    pub fn step_filter_fold(n: u64) -> u64 {
+        spin_on::spin_on(async {
+            stream::iter(0..n)
+                .enumerate()
+                .filter(|(i, _)| future::ready(i % 2 == 0))
+                .map(|(_, i)| i + 1)
+                .filter(|i| future::ready(*i > 512))
+                .fold(0, |acc, i| future::ready(acc + i))
+                .await
+        })
+    }
+
+    pub fn sparse_filter_count(n: u64) -> usize {
+        spin_on::spin_on(async {
+            stream::iter(0..n)
+                .filter(|i| future::ready(i % 128 == 0))
+                .count()
+                .await
+        })
+    }
+
+    pub fn flat_map_sum(n: u64) -> u64 {
+        spin_on::spin_on(async {
+            stream::iter(0..n)
+                .flat_map(|i| stream::iter(0..i % 16))
+                .fold(0, |acc, i| future::ready(acc + i))
+                .await
+        })
+    }
+
+    pub fn map_async_sum(n: u64) -> u64 {
+        spin_on::spin_on(async {
+            stream::iter(0..n)
+                .then(|i| async move { i * 3 })
+                .fold(0, |acc, i| future::ready(acc + i))
+                .await
+        })
+    }
+
+    pub fn split_fold(data: &[u64], threads: usize) -> u64 {
+        std::thread::scope(|s| {
+            let handles: Vec<_> = data
+                .chunks(data.len().div_ceil(threads))
+                .map(|chunk| {
+                    s.spawn(move || {
+                        spin_on::spin_on(async {
+                            stream::iter(chunk)
+                                .map(|i| i.wrapping_mul(3))
+                                .fold(0u64, |acc, i| future::ready(acc.wrapping_add(i)))
+                                .await
+                        })
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .fold(0u64, |acc, h| acc.wrapping_add(h.join().unwrap()))
+        })
+    }
+}
+
+pub mod iter {
+    pub fn step_filter_fold(n: u64) -> u64 {
+        (0..n).step_by(2).map(|i| i + 1).filter(|i| *i > 512).sum()
+    }
+
+    pub fn sparse_filter_count(n: u64) -> usize {
+        (0..n).filter(|i| i % 128 == 0).count()
+    }
+
+    pub fn flat_map_sum(n: u64) -> u64 {
+        (0..n).flat_map(|i| 0..i % 16).sum()
+    }
+
+    pub fn map_async_sum(n: u64) -> u64 {
+        (0..n).map(|i| i * 3).sum()
+    }
+
+    pub fn split_fold(data: &[u64], threads: usize) -> u64 {
+        std::thread::scope(|s| {
+            let handles: Vec<_> = data
+                .chunks(data.len().div_ceil(threads))
+                .map(|chunk| {
+                    s.spawn(move || {
+                        chunk
+                            .iter()
+                            .map(|i| i.wrapping_mul(3))
+                            .fold(0u64, |acc, i| acc.wrapping_add(i))
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .fold(0u64, |acc, h| acc.wrapping_add(h.join().unwrap()))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fusion, futures, iter};
+
+    #[test]
+    fn pipelines_agree() {
+        let n = 10_000;
+        let expect = iter::step_filter_fold(n);
+        assert_eq!(fusion::step_filter_fold(n), expect);
+        assert_eq!(futures::step_filter_fold(n), expect);
+
+        let expect = iter::sparse_filter_count(n);
+        assert_eq!(fusion::sparse_filter_count(n), expect);
+        assert_eq!(futures::sparse_filter_count(n), expect);
+
+        let expect = iter::flat_map_sum(n);
+        assert_eq!(fusion::flat_map_sum(n), expect);
+        assert_eq!(futures::flat_map_sum(n), expect);
+
+        let expect = iter::map_async_sum(n);
+        assert_eq!(fusion::map_async_sum(n), expect);
+        assert_eq!(futures::map_async_sum(n), expect);
+
+        let data: Vec<u64> = (0..n).collect();
+        let expect = iter::split_fold(&data, 4);
+        assert_eq!(fusion::split_fold(&data, 4), expect);
+        assert_eq!(futures::split_fold(&data, 4), expect);
+    }
+}
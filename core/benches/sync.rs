@@ -0,0 +1,137 @@
+#![feature(test)]
+
+extern crate test;
+
+use std::task::Poll;
+
+use fusion_core::{prelude::*, step::Step, stream::source};
+use test::{black_box, Bencher};
+
+const N: u64 = 1 << 16;
+
+/// The same items as `0..n`, from a stream that is not [`Stream::SYNC`], so consumers poll it
+/// through `poll_fold_n()` instead of iterating it.
+fn polled(n: u64) -> impl Stream<Item = u64> {
+    let mut range = 0..n;
+    source::from_fn(move |_| Poll::Ready(range.next().map_or(Step::Done, Step::Ready)))
+}
+
+#[bench]
+fn fold_fusion(b: &mut Bencher) {
+    b.iter(|| {
+        spin_on::spin_on(async {
+            (0..black_box(N))
+                .into_fusion()
+                .step_by(2)
+                .map(|i| i * 3)
+                .filter(|i| i % 7 != 0)
+                .fold(0, |acc, i| acc ^ i)
+                .yield_by(1024)
+                .await
+        })
+    })
+}
+
+#[bench]
+fn fold_fusion_polled(b: &mut Bencher) {
+    b.iter(|| {
+        spin_on::spin_on(async {
+            polled(black_box(N))
+                .step_by(2)
+                .map(|i| i * 3)
+                .filter(|i| i % 7 != 0)
+                .fold(0, |acc, i| acc ^ i)
+                .yield_by(1024)
+                .await
+        })
+    })
+}
+
+#[bench]
+fn fold_std(b: &mut Bencher) {
+    b.iter(|| {
+        (0..black_box(N))
+            .step_by(2)
+            .map(|i| i * 3)
+            .filter(|i| i % 7 != 0)
+            .fold(0, |acc, i| acc ^ i)
+    })
+}
+
+#[bench]
+fn count_fusion(b: &mut Bencher) {
+    b.iter(|| {
+        spin_on::spin_on(async {
+            (0..black_box(N))
+                .into_fusion()
+                .skip(5)
+                .filter(|i| i % 3 == 0)
+                .take(N as usize / 4)
+                .count()
+                .yield_by(1024)
+                .await
+        })
+    })
+}
+
+#[bench]
+fn count_fusion_polled(b: &mut Bencher) {
+    b.iter(|| {
+        spin_on::spin_on(async {
+            polled(black_box(N))
+                .skip(5)
+                .filter(|i| i % 3 == 0)
+                .take(N as usize / 4)
+                .count()
+                .yield_by(1024)
+                .await
+        })
+    })
+}
+
+#[bench]
+fn count_std(b: &mut Bencher) {
+    b.iter(|| {
+        (0..black_box(N))
+            .skip(5)
+            .filter(|i| i % 3 == 0)
+            .take(N as usize / 4)
+            .count()
+    })
+}
+
+#[bench]
+fn collect_fusion(b: &mut Bencher) {
+    b.iter(|| {
+        spin_on::spin_on(async {
+            (0..black_box(N))
+                .into_fusion()
+                .map(|i| i.wrapping_mul(0x9e37_79b9))
+                .collect::<Vec<_>>()
+                .yield_by(1024)
+                .await
+        })
+    })
+}
+
+#[bench]
+fn collect_fusion_polled(b: &mut Bencher) {
+    b.iter(|| {
+        spin_on::spin_on(async {
+            polled(black_box(N))
+                .map(|i| i.wrapping_mul(0x9e37_79b9))
+                .collect::<Vec<_>>()
+                .yield_by(1024)
+                .await
+        })
+    })
+}
+
+#[bench]
+fn collect_std(b: &mut Bencher) {
+    b.iter(|| {
+        (0..black_box(N))
+            .map(|i| i.wrapping_mul(0x9e37_79b9))
+            .collect::<Vec<_>>()
+    })
+}
@@ -15,7 +15,7 @@ fn main() {
             .step_by(2)
             .map(|i| i + 1)
             .filter(|i| *i > 512)
-            .fold(0, |acc, item| acc + item);
+            .sum::<i32>();
 
         assert_eq!(n, expect);
     })
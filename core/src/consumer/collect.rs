@@ -1,5 +1,6 @@
 use core::{
     mem,
+    ops::ControlFlow,
     pin::Pin,
     task::{Context, Poll},
 };
@@ -8,7 +9,10 @@ use futures_core::ready;
 use pin_project_lite::pin_project;
 
 use super::Consumer;
-use crate::{step::Step, stream::Stream};
+use crate::{
+    step::Step,
+    stream::{sync_iter::SyncIter, Stream},
+};
 
 pin_project! {
     #[must_use = "comsumers do nothing unless you execute them"]
@@ -28,14 +32,38 @@ where
 
     fn poll_consume(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Output>> {
         let this = self.project();
-        return Poll::Ready(match ready!(this.stream.poll_next(cx)) {
+        Poll::Ready(match ready!(this.stream.poll_next(cx)) {
             Step::NotYet => None,
             Step::Ready(e) => {
                 this.collection.extend(Some(e));
                 None
             }
             Step::Done => Some(mem::take(this.collection)),
-        });
+        })
+    }
+
+    fn poll_consume_n(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        budget: &mut usize,
+    ) -> Poll<Option<Self::Output>> {
+        let this = self.project();
+        let next = if S::SYNC {
+            let mut end = Poll::Ready(Step::NotYet);
+            SyncIter::new(this.stream, cx, budget, &mut end)
+                .for_each(|e| this.collection.extend(Some(e)));
+            end
+        } else {
+            this.stream
+                .poll_fold_n(cx, budget, this.collection, |collection, e| {
+                    collection.extend(Some(e));
+                    ControlFlow::Continue(())
+                })
+        };
+        Poll::Ready(match ready!(next) {
+            Step::Done => Some(mem::take(this.collection)),
+            _ => None,
+        })
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
@@ -1,4 +1,5 @@
 use core::{
+    ops::ControlFlow,
     pin::Pin,
     task::{Context, Poll},
 };
@@ -7,7 +8,10 @@ use futures_core::ready;
 use pin_project_lite::pin_project;
 
 use super::Consumer;
-use crate::{step::Step, stream::Stream};
+use crate::{
+    step::Step,
+    stream::{sync_iter::SyncIter, Stream},
+};
 
 pin_project! {
     #[must_use = "comsumers do nothing unless you execute them"]
@@ -33,6 +37,28 @@ impl<S: Stream + ?Sized> Consumer for Count<S> {
         })
     }
 
+    fn poll_consume_n(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        budget: &mut usize,
+    ) -> Poll<Option<Self::Output>> {
+        let this = self.project();
+        let next = if S::SYNC {
+            let mut end = Poll::Ready(Step::NotYet);
+            *this.count += SyncIter::new(this.stream, cx, budget, &mut end).count();
+            end
+        } else {
+            this.stream.poll_fold_n(cx, budget, this.count, |count, _| {
+                *count += 1;
+                ControlFlow::Continue(())
+            })
+        };
+        Poll::Ready(match ready!(next) {
+            Step::Done => Some(*this.count),
+            _ => None,
+        })
+    }
+
     fn size_hint(&self) -> (usize, Option<usize>) {
         self.stream.size_hint()
     }
@@ -0,0 +1,64 @@
+use core::{
+    ops::ControlFlow,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::ready;
+use pin_project_lite::pin_project;
+
+use super::Consumer;
+use crate::{step::Step, stream::Stream};
+
+pin_project! {
+    #[must_use = "comsumers do nothing unless you execute them"]
+    pub struct Find<S, P> {
+        #[pin]
+        pub(crate) stream: S,
+        pub(crate) predicate: P,
+    }
+}
+
+impl<S, P> Consumer for Find<S, P>
+where
+    S: Stream,
+    P: FnMut(&S::Item) -> bool,
+{
+    type Output = Option<S::Item>;
+
+    fn poll_consume(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Output>> {
+        let this = self.project();
+        Poll::Ready(match ready!(this.stream.poll_next(cx)) {
+            Step::NotYet => None,
+            Step::Ready(v) => (this.predicate)(&v).then_some(Some(v)),
+            Step::Done => Some(None),
+        })
+    }
+
+    fn poll_consume_n(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        budget: &mut usize,
+    ) -> Poll<Option<Self::Output>> {
+        let this = self.project();
+        let predicate = this.predicate;
+        let mut found = None;
+        let next = ready!(this.stream.poll_fold_n(cx, budget, &mut found, |found, v| {
+            if predicate(&v) {
+                *found = Some(v);
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        }));
+        Poll::Ready(match next {
+            Step::NotYet => None,
+            Step::Ready(()) => Some(found),
+            Step::Done => Some(None),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
@@ -1,4 +1,5 @@
 use core::{
+    ops::ControlFlow,
     pin::Pin,
     task::{Context, Poll},
 };
@@ -7,7 +8,10 @@ use futures_core::ready;
 use pin_project_lite::pin_project;
 
 use super::Consumer;
-use crate::{step::Step, stream::Stream};
+use crate::{
+    step::Step,
+    stream::{sync_iter::SyncIter, Stream},
+};
 
 pin_project! {
     #[must_use = "futures do nothing unless you `.await` or poll them"]
@@ -39,4 +43,29 @@ where
             Step::Done => Some(this.acc.take().unwrap()),
         })
     }
+
+    fn poll_consume_n(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        budget: &mut usize,
+    ) -> Poll<Option<Self::Output>> {
+        let this = self.project();
+        let f = this.f;
+        let next = if S::SYNC {
+            let mut end = Poll::Ready(Step::NotYet);
+            let acc = this.acc.take().unwrap();
+            *this.acc = Some(SyncIter::new(this.stream, cx, budget, &mut end).fold(acc, &mut *f));
+            end
+        } else {
+            this.stream.poll_fold_n(cx, budget, this.acc, |acc, v| {
+                let old = acc.take().unwrap();
+                *acc = Some(f(old, v));
+                ControlFlow::Continue(())
+            })
+        };
+        Poll::Ready(match ready!(next) {
+            Step::Done => Some(this.acc.take().unwrap()),
+            _ => None,
+        })
+    }
 }
@@ -0,0 +1,131 @@
+use core::{
+    ops::ControlFlow,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::ready;
+use pin_project_lite::pin_project;
+
+use super::Consumer;
+use crate::{step::Step, stream::Stream};
+
+pin_project! {
+    #[must_use = "comsumers do nothing unless you execute them"]
+    pub struct FoldWhile<S, F, T> {
+        #[pin]
+        pub(crate) stream: S,
+        pub(crate) f: F,
+        pub(crate) acc: Option<T>,
+    }
+}
+
+impl<S, F, T, B> Consumer for FoldWhile<S, F, T>
+where
+    S: Stream,
+    F: FnMut(T, S::Item) -> ControlFlow<B, T>,
+{
+    type Output = ControlFlow<B, T>;
+
+    fn poll_consume(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Output>> {
+        let this = self.project();
+        Poll::Ready(match ready!(this.stream.poll_next(cx)) {
+            Step::NotYet => None,
+            Step::Ready(v) => {
+                let old = this.acc.take().unwrap();
+                match (this.f)(old, v) {
+                    ControlFlow::Continue(new) => {
+                        *this.acc = Some(new);
+                        None
+                    }
+                    ControlFlow::Break(b) => Some(ControlFlow::Break(b)),
+                }
+            }
+            Step::Done => Some(ControlFlow::Continue(this.acc.take().unwrap())),
+        })
+    }
+
+    fn poll_consume_n(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        budget: &mut usize,
+    ) -> Poll<Option<Self::Output>> {
+        let this = self.project();
+        let f = this.f;
+        let mut brk = None;
+        let next = ready!(this.stream.poll_fold_n(cx, budget, this.acc, |acc, v| {
+            let old = acc.take().unwrap();
+            match f(old, v) {
+                ControlFlow::Continue(new) => {
+                    *acc = Some(new);
+                    ControlFlow::Continue(())
+                }
+                ControlFlow::Break(b) => {
+                    brk = Some(b);
+                    ControlFlow::Break(())
+                }
+            }
+        }));
+        Poll::Ready(match next {
+            Step::NotYet => None,
+            Step::Ready(()) => brk.map(ControlFlow::Break),
+            Step::Done => Some(ControlFlow::Continue(this.acc.take().unwrap())),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::{
+        consumer::ConsumerExt,
+        stream::{ext::StreamExt, IntoFusion},
+    };
+
+    #[test]
+    fn fold_while() {
+        use core::ops::ControlFlow;
+
+        let first_over = |limit| {
+            (1..=5)
+                .into_fusion()
+                .fold_while(0, move |acc, i| match acc + i {
+                    sum if sum > limit => ControlFlow::Break(i),
+                    sum => ControlFlow::Continue(sum),
+                })
+        };
+        for step in [1, 2, 32] {
+            assert_eq!(
+                spin_on::spin_on(first_over(5).yield_by(step)),
+                ControlFlow::Break(3)
+            );
+            assert_eq!(
+                spin_on::spin_on(first_over(100).yield_by(step)),
+                ControlFlow::Continue(15)
+            );
+        }
+        // The stream is not polled past the break.
+        let mut polled = 0;
+        let stream = (1..=5).into_fusion().map(|i| {
+            polled += 1;
+            i
+        });
+        let flow = spin_on::spin_on(
+            stream
+                .fold_while((), |(), i| {
+                    if i == 2 {
+                        ControlFlow::Break(i)
+                    } else {
+                        ControlFlow::Continue(())
+                    }
+                })
+                .yield_by(32),
+        );
+        assert_eq!((flow, polled), (ControlFlow::Break(2), 2));
+    }
+}
@@ -0,0 +1,80 @@
+use core::{
+    ops::ControlFlow,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::ready;
+use pin_project_lite::pin_project;
+
+use super::Consumer;
+use crate::{
+    step::Step,
+    stream::{DoubleEndedStream, Stream},
+};
+
+pin_project! {
+    #[must_use = "comsumers do nothing unless you execute them"]
+    pub struct Last<S: Stream> {
+        #[pin]
+        pub(crate) stream: S,
+        pub(crate) last: Option<S::Item>,
+    }
+}
+
+impl<S: Stream> Consumer for Last<S> {
+    type Output = Option<S::Item>;
+
+    fn poll_consume(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Output>> {
+        let this = self.project();
+        Poll::Ready(match ready!(this.stream.poll_next(cx)) {
+            Step::NotYet => None,
+            Step::Ready(v) => {
+                *this.last = Some(v);
+                None
+            }
+            Step::Done => Some(this.last.take()),
+        })
+    }
+
+    fn poll_consume_n(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        budget: &mut usize,
+    ) -> Poll<Option<Self::Output>> {
+        let this = self.project();
+        let next = ready!(this.stream.poll_fold_n(cx, budget, this.last, |last, v| {
+            *last = Some(v);
+            ControlFlow::Continue(())
+        }));
+        Poll::Ready(match next {
+            Step::Done => Some(this.last.take()),
+            _ => None,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+pin_project! {
+    #[must_use = "comsumers do nothing unless you execute them"]
+    pub struct LastBack<S> {
+        #[pin]
+        pub(crate) stream: S,
+    }
+}
+
+impl<S: DoubleEndedStream> Consumer for LastBack<S> {
+    type Output = Option<S::Item>;
+
+    fn poll_consume(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Output>> {
+        let this = self.project();
+        Poll::Ready(match ready!(this.stream.poll_next_back(cx)) {
+            Step::NotYet => None,
+            Step::Ready(v) => Some(Some(v)),
+            Step::Done => Some(None),
+        })
+    }
+}
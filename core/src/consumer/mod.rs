@@ -1,14 +1,25 @@
 pub mod collect;
 pub mod count;
+pub mod find;
 pub mod fold;
+pub mod fold_while;
+pub mod last;
 pub mod partition;
+pub mod partition_result;
+pub mod stats;
 pub mod try_collect;
+pub mod try_count;
+pub mod try_fold;
+pub mod try_partition;
+pub mod try_reduce;
 
 use core::{
     pin::Pin,
     task::{Context, Poll},
 };
 
+use futures_core::ready;
+
 use crate::execution::yield_by::YieldBy;
 
 #[must_use = "comsumers do nothing unless you execute them"]
@@ -20,6 +31,25 @@ pub trait Consumer {
     fn size_hint(&self) -> (usize, Option<usize>) {
         (0, None)
     }
+
+    /// Consumes at most `budget` steps of the underlying stream, decreasing `budget` by the number
+    /// of steps taken.
+    ///
+    /// Resolves to `None` if the budget runs out before the output is ready.
+    fn poll_consume_n(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        budget: &mut usize,
+    ) -> Poll<Option<Self::Output>> {
+        while *budget > 0 {
+            let output = ready!(self.as_mut().poll_consume(cx));
+            *budget -= 1;
+            if output.is_some() {
+                return Poll::Ready(output);
+            }
+        }
+        Poll::Ready(None)
+    }
 }
 
 pub trait ConsumerExt: Consumer {
@@ -31,7 +61,6 @@ pub trait ConsumerExt: Consumer {
 
         YieldBy {
             consumer: self,
-            n: 0,
             step,
         }
     }
@@ -1,4 +1,5 @@
 use core::{
+    ops::ControlFlow,
     pin::Pin,
     task::{Context, Poll},
 };
@@ -45,6 +46,28 @@ where
         })
     }
 
+    fn poll_consume_n(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        budget: &mut usize,
+    ) -> Poll<Option<Self::Output>> {
+        let this = self.project();
+        let predicate = this.predicate;
+        let res = this.res.as_mut().unwrap();
+        let next = ready!(this.stream.poll_fold_n(cx, budget, res, |res, v| {
+            if predicate(&v) {
+                res.0.extend(Some(v));
+            } else {
+                res.1.extend(Some(v));
+            }
+            ControlFlow::Continue(())
+        }));
+        Poll::Ready(match next {
+            Step::Done => Some(this.res.take().unwrap()),
+            _ => None,
+        })
+    }
+
     fn size_hint(&self) -> (usize, Option<usize>) {
         self.stream.size_hint()
     }
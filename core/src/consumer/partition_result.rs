@@ -0,0 +1,93 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::ready;
+use pin_project_lite::pin_project;
+
+use super::Consumer;
+use crate::{step::Step, stream::Stream};
+
+pin_project! {
+    #[must_use = "comsumers do nothing unless you execute them"]
+    pub struct PartitionResult<S, A, B> {
+        #[pin]
+        pub(crate) stream: S,
+        pub(crate) res: Option<(A, B)>,
+    }
+}
+
+impl<T, E, S, A, B> Consumer for PartitionResult<S, A, B>
+where
+    S: Stream<Item = Result<T, E>>,
+    A: Default + Extend<T>,
+    B: Default + Extend<E>,
+{
+    type Output = (A, B);
+
+    fn poll_consume(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Output>> {
+        let this = self.project();
+        Poll::Ready(match ready!(this.stream.poll_next(cx)) {
+            Step::NotYet => None,
+            Step::Ready(Ok(v)) => {
+                this.res.as_mut().unwrap().0.extend(Some(v));
+                None
+            }
+            Step::Ready(Err(err)) => {
+                this.res.as_mut().unwrap().1.extend(Some(err));
+                None
+            }
+            Step::Done => Some(this.res.take().unwrap()),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::vec::Vec;
+
+    use crate::{
+        consumer::ConsumerExt,
+        stream::{ext::StreamExt, IntoFusion},
+    };
+
+    #[test]
+    fn partition_result() {
+        let results = [Ok(1), Err("a"), Ok(3), Err("b"), Ok(5)];
+        for step in [1, 32] {
+            let (ok, err): (Vec<_>, Vec<_>) = spin_on::spin_on(
+                results
+                    .into_iter()
+                    .into_fusion()
+                    .partition_result()
+                    .yield_by(step),
+            );
+            assert_eq!((ok, err), (std::vec![1, 3, 5], std::vec!["a", "b"]));
+        }
+        #[cfg(feature = "alloc")]
+        {
+            let (ok, err): (Vec<_>, _) = spin_on::spin_on(
+                results
+                    .into_iter()
+                    .into_fusion()
+                    .collect_results()
+                    .yield_by(2),
+            );
+            assert_eq!((ok, err), (std::vec![1, 3, 5], std::vec!["a", "b"]));
+        }
+        let (ok, err): (Vec<i32>, Vec<&str>) = spin_on::spin_on(
+            core::iter::empty::<Result<i32, &str>>()
+                .into_fusion()
+                .partition_result()
+                .yield_by(1),
+        );
+        assert!(ok.is_empty() && err.is_empty());
+    }
+}
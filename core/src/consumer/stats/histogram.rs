@@ -0,0 +1,107 @@
+use alloc::{vec, vec::Vec};
+use core::ops::Range;
+
+use super::{Aggregator, AsF64};
+
+/// Counts of items in equally wide buckets spanning a fixed range.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    range: Range<f64>,
+    counts: Vec<u64>,
+    underflow: u64,
+    overflow: u64,
+}
+
+impl Histogram {
+    /// Splits `range` into `buckets` buckets. Items outside of it are only counted by
+    /// [`underflow()`][`Histogram::underflow()`] and [`overflow()`][`Histogram::overflow()`].
+    pub fn new(range: Range<f64>, buckets: usize) -> Self {
+        assert!(buckets > 0, "`buckets` must be greater than zero");
+        assert!(range.start < range.end, "`range` must not be empty");
+        Histogram {
+            range,
+            counts: vec![0; buckets],
+            underflow: 0,
+            overflow: 0,
+        }
+    }
+
+    /// The number of items in each bucket, in order.
+    #[inline]
+    pub fn counts(&self) -> &[u64] {
+        &self.counts
+    }
+
+    /// The range of values covered by the bucket at `index`.
+    pub fn bucket(&self, index: usize) -> Range<f64> {
+        let width = (self.range.end - self.range.start) / self.counts.len() as f64;
+        let start = self.range.start + width * index as f64;
+        start..start + width
+    }
+
+    /// The number of items below the range.
+    #[inline]
+    pub const fn underflow(&self) -> u64 {
+        self.underflow
+    }
+
+    /// The number of items at or past the end of the range, including `NaN`s.
+    #[inline]
+    pub const fn overflow(&self) -> u64 {
+        self.overflow
+    }
+
+    /// The number of items pushed.
+    pub fn count(&self) -> u64 {
+        self.counts.iter().sum::<u64>() + self.underflow + self.overflow
+    }
+}
+
+impl<T: AsF64> Aggregator<T> for Histogram {
+    #[inline]
+    fn push(&mut self, item: T) {
+        let x = item.to_f64();
+        if x < self.range.start {
+            self.underflow += 1;
+        } else if self.range.contains(&x) {
+            let position = (x - self.range.start) / (self.range.end - self.range.start);
+            // Rounding may push values right below the end into a bucket past the last one.
+            let index = ((position * self.counts.len() as f64) as usize).min(self.counts.len() - 1);
+            self.counts[index] += 1;
+        } else {
+            self.overflow += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::{
+        consumer::ConsumerExt,
+        stream::{ext::StreamExt, IntoFusion},
+    };
+
+    #[test]
+    fn histogram() {
+        let histogram = spin_on::spin_on(
+            (-1..12)
+                .map(f64::from)
+                .into_fusion()
+                .histogram(0.0..10.0, 5)
+                .yield_by(3),
+        );
+        assert_eq!(histogram.counts(), [2, 2, 2, 2, 2]);
+        assert_eq!(histogram.bucket(1), 2.0..4.0);
+        assert_eq!((histogram.underflow(), histogram.overflow()), (1, 2));
+        assert_eq!(histogram.count(), 13);
+        let histogram = spin_on::spin_on(
+            (-1i64..12)
+                .into_fusion()
+                .histogram(0.0..10.0, 5)
+                .yield_by(3),
+        );
+        assert_eq!(histogram.counts(), [2, 2, 2, 2, 2]);
+    }
+}
@@ -0,0 +1,164 @@
+use alloc::{vec, vec::Vec};
+use core::hash::{Hash, Hasher};
+
+use super::Aggregator;
+
+/// Approximate count of distinct items, computed with HyperLogLog.
+///
+/// With a precision of `p`, the sketch takes `2^p` bytes and its standard error is about
+/// `1.04 / sqrt(2^p)`. Items are hashed with a fixed hasher, so sketches built separately, for
+/// example by different shards, can be [merged][`HyperLogLog::merge()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HyperLogLog {
+    precision: u8,
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    pub fn new(precision: u8) -> Self {
+        assert!(
+            (4..=18).contains(&precision),
+            "`precision` must be between 4 and 18"
+        );
+        HyperLogLog {
+            precision,
+            registers: vec![0; 1 << precision],
+        }
+    }
+
+    #[inline]
+    pub const fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    /// The estimated number of distinct items pushed.
+    pub fn count(&self) -> u64 {
+        let m = self.registers.len() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+        let sum: f64 = self
+            .registers
+            .iter()
+            .map(|&rank| 1.0 / (1u64 << rank) as f64)
+            .sum();
+        let estimate = alpha * m * m / sum;
+
+        // Small cardinalities are estimated more precisely by the number of empty registers.
+        let zeros = self.registers.iter().filter(|&&rank| rank == 0).count();
+        let estimate = if estimate <= 2.5 * m && zeros > 0 {
+            m * ln(m / zeros as f64)
+        } else {
+            estimate
+        };
+        (estimate + 0.5) as u64
+    }
+
+    /// Combines two sketches of the same precision, as if all items had been pushed to `self`.
+    pub fn merge(&mut self, other: &HyperLogLog) {
+        assert_eq!(
+            self.precision, other.precision,
+            "sketches of different precisions cannot be merged"
+        );
+        for (rank, other) in self.registers.iter_mut().zip(&other.registers) {
+            *rank = (*rank).max(*other);
+        }
+    }
+}
+
+impl<T: Hash> Aggregator<T> for HyperLogLog {
+    #[inline]
+    fn push(&mut self, item: T) {
+        let mut hasher = FixedHasher(0);
+        item.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let p = self.precision;
+        let index = (hash >> (64 - p)) as usize;
+        // The position of the first set bit among the remaining ones, capped in case all are zero.
+        let rank = ((hash << p) | (1 << (p - 1))).leading_zeros() as u8 + 1;
+        self.registers[index] = self.registers[index].max(rank);
+    }
+}
+
+/// A hasher with a fixed seed, so that every sketch hashes items the same way.
+struct FixedHasher(u64);
+
+impl Hasher for FixedHasher {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(8) {
+            let mut word = [0; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            self.write_u64(u64::from_le_bytes(word));
+        }
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.0 = (self.0.rotate_left(5) ^ i).wrapping_mul(0x51_7c_c1_b7_27_22_0a_95);
+    }
+
+    /// Finalizes with the avalanche step of MurmurHash3, as every bit of the hash is used.
+    #[inline]
+    fn finish(&self) -> u64 {
+        let mut h = self.0;
+        h ^= h >> 33;
+        h = h.wrapping_mul(0xff51_afd7_ed55_8ccd);
+        h ^= h >> 33;
+        h = h.wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+        h ^ (h >> 33)
+    }
+}
+
+/// Natural logarithm of a positive, finite `x`, as `f64::ln` is not available without `std`.
+fn ln(x: f64) -> f64 {
+    // Split `x` into `mantissa * 2^exponent` with the mantissa in `[1, 2)`.
+    let bits = x.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as i64 - 1023;
+    let mantissa = f64::from_bits((bits & ((1 << 52) - 1)) | (1023 << 52));
+    // `ln(m) = 2 * atanh(z)` with `z = (m - 1) / (m + 1)` in `[0, 1/3)`, where the series
+    // converges quickly.
+    let z = (mantissa - 1.0) / (mantissa + 1.0);
+    let z2 = z * z;
+    let (mut term, mut sum) = (z, 0.0);
+    for k in 0..20 {
+        sum += term / (2 * k + 1) as f64;
+        term *= z2;
+    }
+    2.0 * sum + exponent as f64 * core::f64::consts::LN_2
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::{
+        consumer::ConsumerExt,
+        stream::{ext::StreamExt, IntoFusion},
+    };
+
+    #[test]
+    fn hyperloglog() {
+        use crate::consumer::stats::{Aggregator, HyperLogLog};
+
+        let sketch = |range: core::ops::Range<u64>| {
+            spin_on::spin_on(range.into_fusion().approx_count_distinct(14).yield_by(512))
+        };
+        assert!(sketch(0..100).count().abs_diff(100) <= 2);
+        let all = sketch(0..100_000);
+        assert!(all.count().abs_diff(100_000) < 3000);
+        // Shards seeing overlapping items merge into the sketch of their union.
+        let mut merged = sketch(0..60_000);
+        merged.merge(&sketch(40_000..100_000));
+        assert_eq!(merged, all);
+        let mut strings = HyperLogLog::new(10);
+        ["a", "b", "a", "c"]
+            .into_iter()
+            .for_each(|s| strings.push(s));
+        assert_eq!(strings.count(), 3);
+    }
+}
@@ -0,0 +1,352 @@
+//! Incremental summaries of the items of a stream.
+//!
+//! Every summary is an [`Aggregator`]. [`aggregate()`][`StreamExt::aggregate()`] consumes a
+//! stream into one, while [`running()`][`StreamExt::running()`] yields a view of it after every
+//! item.
+//!
+//! [`StreamExt::aggregate()`]: crate::stream::ext::StreamExt::aggregate()
+//! [`StreamExt::running()`]: crate::stream::ext::StreamExt::running()
+
+#[cfg(feature = "alloc")]
+mod histogram;
+#[cfg(feature = "alloc")]
+mod hyperloglog;
+#[cfg(feature = "alloc")]
+mod quantiles;
+#[cfg(feature = "alloc")]
+mod reservoir;
+#[cfg(feature = "alloc")]
+mod top_k;
+
+use core::{
+    ops::ControlFlow,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::ready;
+#[cfg(feature = "alloc")]
+pub use histogram::Histogram;
+#[cfg(feature = "alloc")]
+pub use hyperloglog::HyperLogLog;
+use pin_project_lite::pin_project;
+#[cfg(feature = "alloc")]
+pub use quantiles::Quantiles;
+#[cfg(feature = "alloc")]
+pub use reservoir::Reservoir;
+#[cfg(feature = "alloc")]
+pub use top_k::TopK;
+
+use super::Consumer;
+use crate::{
+    step::Step,
+    stream::{sync_iter::SyncIter, Stream},
+};
+
+/// A summary that is updated one item at a time.
+pub trait Aggregator<T> {
+    fn push(&mut self, item: T);
+}
+
+/// A number that numeric summaries such as [`Moments`] accept, converted with `as`.
+///
+/// Integers wider than 53 bits may lose precision.
+pub trait AsF64 {
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! as_f64 {
+    ($($t:ty)*) => {
+        $(
+            impl AsF64 for $t {
+                #[inline]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+as_f64!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64);
+
+impl<T: AsF64 + Copy> AsF64 for &T {
+    #[inline]
+    fn to_f64(self) -> f64 {
+        (*self).to_f64()
+    }
+}
+
+/// Count, mean and variance, computed with Welford's algorithm.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Moments {
+    count: u64,
+    mean: f64,
+    /// Sum of squared differences from the mean.
+    m2: f64,
+}
+
+impl Moments {
+    pub const fn new() -> Self {
+        Moments {
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+        }
+    }
+
+    #[inline]
+    pub const fn count(&self) -> u64 {
+        self.count
+    }
+
+    #[inline]
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.mean)
+    }
+
+    /// The population variance.
+    #[inline]
+    pub fn variance(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.m2 / self.count as f64)
+    }
+
+    /// The sample variance, with Bessel's correction.
+    #[inline]
+    pub fn sample_variance(&self) -> Option<f64> {
+        (self.count > 1).then(|| self.m2 / (self.count - 1) as f64)
+    }
+
+    /// The population standard deviation.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn std_dev(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+
+    /// Combines the moments of two disjoint sets of items, as if all of them had been pushed to
+    /// `self`.
+    pub fn merge(&mut self, other: &Moments) {
+        if other.count == 0 {
+            return;
+        }
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        let weight = other.count as f64 / count as f64;
+        self.mean += delta * weight;
+        self.m2 += other.m2 + delta * delta * self.count as f64 * weight;
+        self.count = count;
+    }
+}
+
+impl<T: AsF64> Aggregator<T> for Moments {
+    #[inline]
+    fn push(&mut self, item: T) {
+        let x = item.to_f64();
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x - self.mean);
+    }
+}
+
+/// The smallest and largest items.
+///
+/// Items that are not comparable to the current extremes, such as `NaN`, are ignored unless they
+/// come first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MinMax<T> {
+    bounds: Option<(T, T)>,
+}
+
+impl<T> MinMax<T> {
+    pub const fn new() -> Self {
+        MinMax { bounds: None }
+    }
+
+    #[inline]
+    pub fn min(&self) -> Option<&T> {
+        self.bounds.as_ref().map(|(min, _)| min)
+    }
+
+    #[inline]
+    pub fn max(&self) -> Option<&T> {
+        self.bounds.as_ref().map(|(_, max)| max)
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> Option<(T, T)> {
+        self.bounds
+    }
+}
+
+impl<T> Default for MinMax<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PartialOrd + Clone> Aggregator<T> for MinMax<T> {
+    #[inline]
+    fn push(&mut self, item: T) {
+        match &mut self.bounds {
+            None => self.bounds = Some((item.clone(), item)),
+            Some((min, _)) if item < *min => *min = item,
+            Some((_, max)) if item > *max => *max = item,
+            Some(_) => {}
+        }
+    }
+}
+
+pin_project! {
+    #[must_use = "comsumers do nothing unless you execute them"]
+    pub struct Aggregate<S, A> {
+        #[pin]
+        pub(crate) stream: S,
+        pub(crate) aggregator: Option<A>,
+    }
+}
+
+impl<S, A> Consumer for Aggregate<S, A>
+where
+    S: Stream,
+    A: Aggregator<S::Item>,
+{
+    type Output = A;
+
+    fn poll_consume(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Output>> {
+        let this = self.project();
+        Poll::Ready(match ready!(this.stream.poll_next(cx)) {
+            Step::NotYet => None,
+            Step::Ready(item) => {
+                this.aggregator.as_mut().unwrap().push(item);
+                None
+            }
+            Step::Done => Some(this.aggregator.take().unwrap()),
+        })
+    }
+
+    fn poll_consume_n(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        budget: &mut usize,
+    ) -> Poll<Option<Self::Output>> {
+        let this = self.project();
+        let aggregator = this.aggregator.as_mut().unwrap();
+        let next = if S::SYNC {
+            let mut end = Poll::Ready(Step::NotYet);
+            SyncIter::new(this.stream, cx, budget, &mut end).for_each(|item| aggregator.push(item));
+            end
+        } else {
+            this.stream
+                .poll_fold_n(cx, budget, aggregator, |aggregator, item| {
+                    aggregator.push(item);
+                    ControlFlow::Continue(())
+                })
+        };
+        Poll::Ready(match ready!(next) {
+            Step::Done => this.aggregator.take(),
+            _ => None,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Running<S, A, F> {
+        #[pin]
+        pub(crate) stream: S,
+        pub(crate) aggregator: A,
+        pub(crate) f: F,
+    }
+}
+
+impl<S, A, F, T> Stream for Running<S, A, F>
+where
+    S: Stream,
+    A: Aggregator<S::Item>,
+    F: FnMut(&A) -> T,
+{
+    type Item = T;
+
+    const SYNC: bool = S::SYNC;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let this = self.project();
+        Poll::Ready(match ready!(this.stream.poll_next(cx)) {
+            Step::NotYet => Step::NotYet,
+            Step::Ready(item) => {
+                this.aggregator.push(item);
+                Step::Ready((this.f)(this.aggregator))
+            }
+            Step::Done => Step::Done,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::vec::Vec;
+
+    use crate::{
+        consumer::ConsumerExt,
+        stream::{ext::StreamExt, tests::drain, IntoFusion},
+    };
+
+    #[test]
+    fn stats() {
+        use crate::consumer::stats::{Aggregator, Moments};
+
+        let values = || (0..1000).map(|i| (i * 7919 % 1000) as f64);
+        let (mean, variance) = (499.5, (1000.0 * 1000.0 - 1.0) / 12.0);
+        for step in [1, 7, 4096] {
+            let moments = spin_on::spin_on(values().into_fusion().moments().yield_by(step));
+            assert_eq!(moments.count(), 1000);
+            assert!((moments.mean().unwrap() - mean).abs() < 1e-9);
+            assert!((moments.variance().unwrap() - variance).abs() < 1e-6);
+        }
+        let (mut low, mut high) = (Moments::new(), Moments::new());
+        values().take(300).for_each(|v| low.push(v));
+        values().skip(300).for_each(|v| high.push(v));
+        low.merge(&high);
+        assert!((low.mean().unwrap() - mean).abs() < 1e-9);
+        assert!((low.variance().unwrap() - variance).abs() < 1e-6);
+        assert_eq!(Moments::new().mean(), None);
+
+        // Integers of any width, and references to them, are accepted.
+        let ints = spin_on::spin_on((1..=5u64).into_fusion().moments().yield_by(2));
+        assert_eq!((ints.mean(), ints.variance()), (Some(3.0), Some(2.0)));
+        let wide = [i128::MIN, 0, i128::MAX];
+        let wide = spin_on::spin_on(wide.iter().into_fusion().moments().yield_by(2));
+        assert_eq!(wide.mean(), Some(0.0));
+        let sizes = spin_on::spin_on([1usize, 2, 6].iter().into_fusion().moments().yield_by(1));
+        assert_eq!(sizes.mean(), Some(3.0));
+
+        let bounds = spin_on::spin_on(
+            [2.0, f64::NAN, -1.0, 5.0]
+                .into_iter()
+                .into_fusion()
+                .min_max()
+                .yield_by(1),
+        );
+        assert_eq!(bounds.into_inner(), Some((-1.0, 5.0)));
+
+        let running: Vec<_> = drain(
+            [3, 1, 4, 1, 5]
+                .into_iter()
+                .into_fusion()
+                .running(Moments::new(), |m| m.count()),
+        );
+        assert_eq!(running, [1, 2, 3, 4, 5]);
+    }
+}
@@ -0,0 +1,139 @@
+use alloc::vec::Vec;
+use core::mem;
+
+use super::Aggregator;
+
+/// Approximate quantiles, computed with the Greenwald-Khanna sketch.
+///
+/// The rank of the item returned for a quantile is off by at most `epsilon` times the number of
+/// items pushed, while only `O(log(epsilon * n) / epsilon)` of them are kept.
+#[derive(Debug, Clone)]
+pub struct Quantiles<T> {
+    tuples: Vec<Tuple<T>>,
+    count: u64,
+    epsilon: f64,
+}
+
+#[derive(Debug, Clone)]
+struct Tuple<T> {
+    value: T,
+    /// Difference between the lowest possible rank of this value and of the previous one.
+    g: u64,
+    /// Difference between the highest and lowest possible rank of this value.
+    delta: u64,
+}
+
+impl<T> Quantiles<T> {
+    pub fn new(epsilon: f64) -> Self {
+        assert!(
+            epsilon > 0.0 && epsilon < 1.0,
+            "`epsilon` must be between zero and one"
+        );
+        Quantiles {
+            tuples: Vec::new(),
+            count: 0,
+            epsilon,
+        }
+    }
+
+    #[inline]
+    pub const fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns an item whose rank is within `epsilon * count` of `q * count`, or `None` if no
+    /// item has been pushed.
+    ///
+    /// The smallest and largest items are exact at `q` of `0.0` and `1.0`.
+    pub fn quantile(&self, q: f64) -> Option<&T> {
+        let rank = q.clamp(0.0, 1.0) * self.count as f64;
+        let margin = self.epsilon * self.count as f64;
+        let mut min_rank = 0;
+        for (i, tuple) in self.tuples.iter().enumerate() {
+            min_rank += tuple.g;
+            if (min_rank + tuple.delta) as f64 > rank + margin {
+                return Some(&self.tuples[i.saturating_sub(1)].value);
+            }
+        }
+        self.tuples.last().map(|tuple| &tuple.value)
+    }
+
+    /// The largest `g + delta` any tuple may have for ranks to stay within bounds.
+    fn capacity(&self) -> u64 {
+        (2.0 * self.epsilon * self.count as f64) as u64
+    }
+
+    /// Merges neighbouring tuples as long as the merged one stays within
+    /// [`capacity()`][`Quantiles::capacity()`], keeping the smallest and largest items.
+    fn compress(&mut self) {
+        let capacity = self.capacity();
+        let mut tuples = mem::take(&mut self.tuples).into_iter().rev();
+        let mut merged = Vec::with_capacity(tuples.len());
+        merged.extend(tuples.next());
+        while let Some(tuple) = tuples.next() {
+            let next: &mut Tuple<T> = merged.last_mut().unwrap();
+            if tuples.len() > 0 && tuple.g + next.g + next.delta <= capacity {
+                next.g += tuple.g;
+            } else {
+                merged.push(tuple);
+            }
+        }
+        merged.reverse();
+        self.tuples = merged;
+    }
+}
+
+impl<T: PartialOrd> Aggregator<T> for Quantiles<T> {
+    fn push(&mut self, item: T) {
+        let i = self.tuples.partition_point(|tuple| tuple.value <= item);
+        // A new minimum or maximum has an exact rank. Otherwise the rank is as uncertain as that of
+        // the next tuple.
+        let delta = match self.tuples.get(i) {
+            Some(next) if i > 0 => next.g + next.delta - 1,
+            _ => 0,
+        };
+        self.tuples.insert(
+            i,
+            Tuple {
+                value: item,
+                g: 1,
+                delta,
+            },
+        );
+        self.count += 1;
+
+        let period = ((1.0 / (2.0 * self.epsilon)) as u64).max(1);
+        if self.count.is_multiple_of(period) {
+            self.compress();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::{
+        consumer::ConsumerExt,
+        stream::{ext::StreamExt, IntoFusion},
+    };
+
+    #[test]
+    fn quantiles() {
+        let n = 10_000;
+        let quantiles = spin_on::spin_on(
+            (0..n)
+                .map(|i| i * 7919 % n)
+                .into_fusion()
+                .quantiles(0.01)
+                .yield_by(64),
+        );
+        assert_eq!(quantiles.count(), n as u64);
+        assert_eq!(quantiles.quantile(0.0), Some(&0));
+        assert_eq!(quantiles.quantile(1.0), Some(&(n - 1)));
+        for q in [0.1, 0.25, 0.5, 0.9, 0.99] {
+            let rank = *quantiles.quantile(q).unwrap() as f64;
+            assert!((rank - q * n as f64).abs() <= 0.01 * n as f64 + 1.0);
+        }
+    }
+}
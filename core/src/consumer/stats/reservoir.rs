@@ -0,0 +1,109 @@
+use alloc::vec::Vec;
+
+use super::Aggregator;
+use crate::rng::Rng;
+
+/// A uniform random sample of at most `k` items, kept with reservoir sampling.
+///
+/// Every item pushed has the same chance of being part of the sample, without knowing the
+/// number of items in advance.
+#[derive(Debug, Clone)]
+pub struct Reservoir<T, R> {
+    k: usize,
+    rng: R,
+    seen: u64,
+    samples: Vec<T>,
+}
+
+impl<T, R> Reservoir<T, R> {
+    pub fn new(k: usize, rng: R) -> Self {
+        assert!(k > 0, "`k` must be greater than zero");
+        Reservoir {
+            k,
+            rng,
+            seen: 0,
+            samples: Vec::with_capacity(k),
+        }
+    }
+
+    /// The number of items pushed.
+    #[inline]
+    pub const fn seen(&self) -> u64 {
+        self.seen
+    }
+
+    /// The sampled items, in no particular order.
+    #[inline]
+    pub fn samples(&self) -> &[T] {
+        &self.samples
+    }
+
+    #[inline]
+    pub fn into_samples(self) -> Vec<T> {
+        self.samples
+    }
+}
+
+impl<T, R: Rng> Aggregator<T> for Reservoir<T, R> {
+    #[inline]
+    fn push(&mut self, item: T) {
+        self.seen += 1;
+        if self.samples.len() < self.k {
+            self.samples.push(item);
+            return;
+        }
+        // The item replaces a sample with a chance of `k / seen`.
+        let i = self.rng.gen_below(self.seen);
+        // An index past `usize::MAX` is past `k` as well.
+        let sample = usize::try_from(i)
+            .ok()
+            .and_then(|i| self.samples.get_mut(i));
+        if let Some(sample) = sample {
+            *sample = item;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use core::task::Poll;
+
+    use crate::{consumer::ConsumerExt, step::Step, stream::ext::StreamExt};
+
+    #[test]
+    fn sample_reservoir() {
+        use crate::{rng::SplitMix64, stream::source};
+
+        let counter = |n| {
+            let mut i = 0;
+            source::from_fn(move |_| {
+                i += 1;
+                Poll::Ready(if i <= n { Step::Ready(i) } else { Step::Done })
+            })
+        };
+        let sample = |n, k, seed| {
+            spin_on::spin_on(
+                counter(n)
+                    .sample_reservoir(k, SplitMix64::new(seed))
+                    .yield_by(16),
+            )
+        };
+
+        let mut few = sample(3, 5, 0).into_samples();
+        few.sort_unstable();
+        assert_eq!(few, [1, 2, 3]);
+
+        // Every item is picked about `2000 * 5 / 50` times.
+        let mut picks = [0; 50];
+        for seed in 0..2000 {
+            let reservoir = sample(50, 5, seed);
+            assert_eq!((reservoir.seen(), reservoir.samples().len()), (50, 5));
+            for &i in reservoir.samples() {
+                picks[i - 1] += 1;
+            }
+        }
+        assert!(picks.iter().all(|picks| (140..=260).contains(picks)));
+    }
+}
@@ -0,0 +1,162 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::hash::Hash;
+
+use hashbrown::HashMap;
+
+use super::Aggregator;
+
+/// The most frequent items, tracked with the Space-Saving algorithm.
+///
+/// Only `capacity` items are counted at a time. A new item takes over the counter of the least
+/// frequent one, so counts may be overestimated, by at most
+/// [`error_bound()`][`TopK::error_bound()`]. Every item occurring more than `n / capacity` times
+/// out of `n` is tracked.
+#[derive(Debug, Clone)]
+pub struct TopK<T> {
+    capacity: usize,
+    // The count of every tracked item, and the key of its counter in `order`.
+    counts: HashMap<T, (u64, u64)>,
+    // The tracked items by count, ties broken by when their counter was taken.
+    order: BTreeMap<(u64, u64), T>,
+    next_seq: u64,
+}
+
+impl<T> TopK<T> {
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "`capacity` must be greater than zero");
+        TopK {
+            capacity,
+            counts: HashMap::with_capacity(capacity),
+            order: BTreeMap::new(),
+            next_seq: 0,
+        }
+    }
+
+    /// The tracked items along with their estimated counts, most frequent first.
+    pub fn top(&self) -> Vec<(&T, u64)> {
+        self.order
+            .iter()
+            .rev()
+            .map(|(&(count, _), item)| (item, count))
+            .collect()
+    }
+
+    /// The most any estimated count may exceed the actual count by.
+    pub fn error_bound(&self) -> u64 {
+        self.min_count()
+    }
+
+    /// The count an untracked item may have had, which is zero until all counters are in use.
+    fn min_count(&self) -> u64 {
+        if self.order.len() < self.capacity {
+            return 0;
+        }
+        self.order
+            .first_key_value()
+            .map_or(0, |(&(count, _), _)| count)
+    }
+}
+
+impl<T: Eq + Hash + Clone> TopK<T> {
+    /// Combines two summaries, as if all items had been pushed to `self`, keeping the capacity of
+    /// `self`.
+    pub fn merge(&mut self, other: &TopK<T>) {
+        let (min, other_min) = (self.min_count(), other.min_count());
+        let mut counts: HashMap<T, u64> = self
+            .counts
+            .drain()
+            .map(|(item, (count, _))| {
+                let missed = if other.counts.contains_key(&item) {
+                    0
+                } else {
+                    other_min
+                };
+                (item, count + missed)
+            })
+            .collect();
+        for (item, &(count, _)) in &other.counts {
+            *counts.entry(item.clone()).or_insert(min) += count;
+        }
+
+        let mut counts: Vec<_> = counts.into_iter().collect();
+        counts.sort_unstable_by(|(_, a), (_, b)| b.cmp(a));
+        counts.truncate(self.capacity);
+        self.order.clear();
+        for (item, count) in counts {
+            self.track(item, count);
+        }
+    }
+
+    fn track(&mut self, item: T, count: u64) {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        self.counts.insert(item.clone(), (count, seq));
+        self.order.insert((count, seq), item);
+    }
+}
+
+impl<T: Eq + Hash + Clone> Aggregator<T> for TopK<T> {
+    fn push(&mut self, item: T) {
+        if let Some((count, seq)) = self.counts.get_mut(&item) {
+            let item = self.order.remove(&(*count, *seq)).unwrap();
+            *count += 1;
+            self.order.insert((*count, *seq), item);
+        } else if self.order.len() < self.capacity {
+            self.track(item, 1);
+        } else {
+            let ((min, _), evicted) = self.order.pop_first().unwrap();
+            self.counts.remove(&evicted);
+            self.track(item, min + 1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::vec::Vec;
+
+    use crate::{
+        consumer::ConsumerExt,
+        stream::{ext::StreamExt, IntoFusion},
+    };
+
+    #[test]
+    fn top_k() {
+        use crate::consumer::stats::{Aggregator, TopK};
+
+        // Frequent items interleaved with items occurring once, which differ between shards.
+        let items = |shard: u64| {
+            (0..1000).flat_map(move |i| {
+                let heavy = match i % 10 {
+                    0..=4 => 1,
+                    5..=7 => 2,
+                    _ => 3,
+                };
+                core::iter::once(heavy).chain((i % 2 == shard).then_some(100 + i))
+            })
+        };
+        let top =
+            |shard| spin_on::spin_on(items(shard).into_fusion().top_k_frequent(20).yield_by(64));
+        let mut merged = top(0);
+        merged.merge(&top(1));
+        for (summary, shards) in [(top(0), 1), (merged, 2)] {
+            let top: Vec<_> = summary.top().into_iter().take(3).collect();
+            assert_eq!(
+                top.iter().map(|(item, _)| **item).collect::<Vec<_>>(),
+                [1, 2, 3]
+            );
+            for ((_, count), actual) in top.into_iter().zip([500, 300, 200]) {
+                let actual = actual * shards;
+                assert!(count >= actual && count - actual <= summary.error_bound());
+            }
+        }
+
+        // The oldest of the least frequent counters is taken over first.
+        let mut top = TopK::new(2);
+        "aabcb".chars().for_each(|c| top.push(c));
+        assert_eq!(top.top(), [(&'b', 3), (&'c', 2)]);
+        assert_eq!(top.error_bound(), 2);
+    }
+}
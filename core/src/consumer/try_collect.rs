@@ -28,7 +28,7 @@ where
 
     fn poll_consume(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Output>> {
         let this = self.project();
-        return Poll::Ready(match ready!(this.stream.poll_next(cx)) {
+        Poll::Ready(match ready!(this.stream.poll_next(cx)) {
             Step::NotYet => None,
             Step::Ready(e) => match e {
                 Ok(e) => {
@@ -38,7 +38,7 @@ where
                 Err(err) => Some(Err((err, mem::take(this.collection)))),
             },
             Step::Done => Some(Ok(mem::take(this.collection))),
-        });
+        })
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
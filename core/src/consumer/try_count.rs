@@ -0,0 +1,43 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::ready;
+use pin_project_lite::pin_project;
+
+use super::Consumer;
+use crate::{step::Step, stream::Stream};
+
+pin_project! {
+    #[must_use = "comsumers do nothing unless you execute them"]
+    pub struct TryCount<S: ?Sized> {
+        pub(crate) count: usize,
+        #[pin]
+        pub(crate) stream: S,
+    }
+}
+
+impl<T, E, S> Consumer for TryCount<S>
+where
+    S: Stream<Item = Result<T, E>> + ?Sized,
+{
+    type Output = Result<usize, (E, usize)>;
+
+    fn poll_consume(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Output>> {
+        let this = self.project();
+        Poll::Ready(match ready!(this.stream.poll_next(cx)) {
+            Step::NotYet => None,
+            Step::Ready(Ok(_)) => {
+                *this.count += 1;
+                None
+            }
+            Step::Ready(Err(err)) => Some(Err((err, *this.count))),
+            Step::Done => Some(Ok(*this.count)),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
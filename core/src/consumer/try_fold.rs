@@ -0,0 +1,220 @@
+use core::{
+    ops::ControlFlow,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::ready;
+use pin_project_lite::pin_project;
+
+use super::Consumer;
+use crate::{step::Step, stream::Stream};
+
+/// A value that either carries on with an output or stops, like `Result` and `ControlFlow`.
+///
+/// A stable stand-in for `core::ops::Try`, used by [`TryFold`] and
+/// [`TryReduce`][`super::try_reduce::TryReduce`].
+pub trait Try: Sized {
+    type Output;
+
+    fn from_output(output: Self::Output) -> Self;
+
+    /// Splits `self` into the output to carry on with, or itself if it stops.
+    fn branch(self) -> ControlFlow<Self, Self::Output>;
+}
+
+impl<T, E> Try for Result<T, E> {
+    type Output = T;
+
+    #[inline]
+    fn from_output(output: T) -> Self {
+        Ok(output)
+    }
+
+    #[inline]
+    fn branch(self) -> ControlFlow<Self, T> {
+        match self {
+            Ok(output) => ControlFlow::Continue(output),
+            err => ControlFlow::Break(err),
+        }
+    }
+}
+
+impl<B, C> Try for ControlFlow<B, C> {
+    type Output = C;
+
+    #[inline]
+    fn from_output(output: C) -> Self {
+        ControlFlow::Continue(output)
+    }
+
+    #[inline]
+    fn branch(self) -> ControlFlow<Self, C> {
+        match self {
+            ControlFlow::Continue(output) => ControlFlow::Continue(output),
+            flow => ControlFlow::Break(flow),
+        }
+    }
+}
+
+pin_project! {
+    #[must_use = "comsumers do nothing unless you execute them"]
+    pub struct TryFold<S, F, T> {
+        #[pin]
+        pub(crate) stream: S,
+        pub(crate) f: F,
+        pub(crate) acc: Option<T>,
+    }
+}
+
+impl<I, E, S, F, T, R> Consumer for TryFold<S, F, T>
+where
+    S: Stream<Item = Result<I, E>>,
+    F: FnMut(T, I) -> R,
+    R: Try<Output = T>,
+{
+    type Output = Result<R, (E, T)>;
+
+    fn poll_consume(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Output>> {
+        let this = self.project();
+        Poll::Ready(match ready!(this.stream.poll_next(cx)) {
+            Step::NotYet => None,
+            Step::Ready(Ok(item)) => match (this.f)(this.acc.take().unwrap(), item).branch() {
+                ControlFlow::Continue(acc) => {
+                    *this.acc = Some(acc);
+                    None
+                }
+                ControlFlow::Break(r) => Some(Ok(r)),
+            },
+            Step::Ready(Err(err)) => Some(Err((err, this.acc.take().unwrap()))),
+            Step::Done => Some(Ok(R::from_output(this.acc.take().unwrap()))),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::vec::Vec;
+
+    use crate::{
+        consumer::ConsumerExt,
+        stream::{ext::StreamExt, IntoFusion, Stream},
+    };
+
+    #[test]
+    fn try_consumers() {
+        use core::ops::ControlFlow;
+
+        fn results<'a>(
+            items: &'a [Result<i32, &'static str>],
+        ) -> impl Stream<Item = Result<i32, &'static str>> + 'a {
+            items.iter().copied().into_fusion()
+        }
+
+        let ok = [Ok(1), Ok(2), Ok(3)];
+        let err = [Ok(1), Ok(2), Err("error"), Ok(4)];
+
+        for step in [1, 32] {
+            // `try_fold` stops at the first stream error with the partial accumulator.
+            assert_eq!(
+                spin_on::spin_on(
+                    results(&ok)
+                        .try_fold(0, |acc, i| Ok::<_, ()>(acc + i))
+                        .yield_by(step)
+                ),
+                Ok(Ok(6))
+            );
+            assert_eq!(
+                spin_on::spin_on(
+                    results(&err)
+                        .try_fold(0, |acc, i| Ok::<_, ()>(acc + i))
+                        .yield_by(step)
+                ),
+                Err(("error", 3))
+            );
+            // Errors of the closure have their own type and stop the fold.
+            assert_eq!(
+                spin_on::spin_on(
+                    results(&ok)
+                        .try_fold(0u8, |acc, i| if i < 3 { Ok(acc + i as u8) } else { Err(i) })
+                        .yield_by(step)
+                ),
+                Ok(Err(3))
+            );
+            assert_eq!(
+                spin_on::spin_on(
+                    results(&ok)
+                        .try_fold(0, |acc, i| match acc + i {
+                            sum if sum > 2 => ControlFlow::Break(sum),
+                            sum => ControlFlow::Continue(sum),
+                        })
+                        .yield_by(step)
+                ),
+                Ok(ControlFlow::Break(3))
+            );
+
+            assert_eq!(
+                spin_on::spin_on(
+                    results(&ok)
+                        .try_reduce(|a, b| Ok::<_, ()>(a * b))
+                        .yield_by(step)
+                ),
+                Ok(Some(Ok(6)))
+            );
+            assert_eq!(
+                spin_on::spin_on(
+                    results(&[])
+                        .try_reduce(|a, b| Ok::<_, ()>(a * b))
+                        .yield_by(step)
+                ),
+                Ok(None)
+            );
+            assert_eq!(
+                spin_on::spin_on(
+                    results(&err)
+                        .try_reduce(|a, b| Ok::<_, ()>(a + b))
+                        .yield_by(step)
+                ),
+                Err(("error", Some(3)))
+            );
+            assert_eq!(
+                spin_on::spin_on(
+                    results(&[Err("error")])
+                        .try_reduce(|a, b| Ok::<_, ()>(a + b))
+                        .yield_by(step)
+                ),
+                Err(("error", None))
+            );
+            assert_eq!(
+                spin_on::spin_on(
+                    results(&ok)
+                        .try_reduce(|a, b| if b < 3 { Ok(a + b) } else { Err(a) })
+                        .yield_by(step)
+                ),
+                Ok(Some(Err(3)))
+            );
+
+            assert_eq!(
+                spin_on::spin_on(results(&ok).try_count().yield_by(step)),
+                Ok(3)
+            );
+            assert_eq!(
+                spin_on::spin_on(results(&err).try_count().yield_by(step)),
+                Err(("error", 2))
+            );
+
+            let partition: Result<(Vec<_>, Vec<_>), _> =
+                spin_on::spin_on(results(&ok).try_partition(|i| i % 2 == 1).yield_by(step));
+            assert_eq!(partition, Ok((std::vec![1, 3], std::vec![2])));
+            let partition: Result<(Vec<_>, Vec<_>), _> =
+                spin_on::spin_on(results(&err).try_partition(|i| i % 2 == 1).yield_by(step));
+            assert_eq!(partition, Err(("error", (std::vec![1], std::vec![2]))));
+        }
+    }
+}
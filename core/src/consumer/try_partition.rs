@@ -0,0 +1,52 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::ready;
+use pin_project_lite::pin_project;
+
+use super::Consumer;
+use crate::{step::Step, stream::Stream};
+
+pin_project! {
+    #[must_use = "comsumers do nothing unless you execute them"]
+    pub struct TryPartition<S, P, B> {
+        #[pin]
+        pub(crate) stream: S,
+        pub(crate) predicate: P,
+        pub(crate) res: Option<(B, B)>,
+    }
+}
+
+impl<T, E, S, P, B> Consumer for TryPartition<S, P, B>
+where
+    S: Stream<Item = Result<T, E>> + Sized,
+    P: FnMut(&T) -> bool,
+    B: Default + Extend<T>,
+{
+    type Output = Result<(B, B), (E, (B, B))>;
+
+    fn poll_consume(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Output>> {
+        let mut this = self.project();
+
+        Poll::Ready(match ready!(this.stream.as_mut().poll_next(cx)) {
+            Step::NotYet => None,
+            Step::Ready(Ok(v)) => {
+                let res = this.res.as_mut().unwrap();
+                if (this.predicate)(&v) {
+                    res.0.extend(Some(v));
+                } else {
+                    res.1.extend(Some(v));
+                }
+                None
+            }
+            Step::Ready(Err(err)) => Some(Err((err, this.res.take().unwrap()))),
+            Step::Done => Some(Ok(this.res.take().unwrap())),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
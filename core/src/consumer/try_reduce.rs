@@ -0,0 +1,57 @@
+use core::{
+    mem,
+    ops::ControlFlow,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::ready;
+use pin_project_lite::pin_project;
+
+use super::{try_fold::Try, Consumer};
+use crate::{step::Step, stream::Stream};
+
+pin_project! {
+    #[must_use = "comsumers do nothing unless you execute them"]
+    pub struct TryReduce<S, F, T> {
+        #[pin]
+        pub(crate) stream: S,
+        pub(crate) f: F,
+        pub(crate) acc: Option<T>,
+    }
+}
+
+impl<T, E, S, F, R> Consumer for TryReduce<S, F, T>
+where
+    S: Stream<Item = Result<T, E>>,
+    F: FnMut(T, T) -> R,
+    R: Try<Output = T>,
+{
+    type Output = Result<Option<R>, (E, Option<T>)>;
+
+    fn poll_consume(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Output>> {
+        let this = self.project();
+        Poll::Ready(match ready!(this.stream.poll_next(cx)) {
+            Step::NotYet => None,
+            Step::Ready(Ok(item)) => match this.acc.take() {
+                None => {
+                    *this.acc = Some(item);
+                    None
+                }
+                Some(acc) => match (this.f)(acc, item).branch() {
+                    ControlFlow::Continue(acc) => {
+                        *this.acc = Some(acc);
+                        None
+                    }
+                    ControlFlow::Break(r) => Some(Ok(Some(r))),
+                },
+            },
+            Step::Ready(Err(err)) => Some(Err((err, mem::take(this.acc)))),
+            Step::Done => Some(Ok(this.acc.take().map(R::from_output))),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
@@ -4,39 +4,57 @@ use core::{
     task::{Context, Poll},
 };
 
+use pin_project_lite::pin_project;
+
 use crate::consumer::Consumer;
 
-#[must_use = "futures do nothing unless polled"]
-pub struct YieldBy<C: Consumer> {
-    pub(crate) consumer: C,
-    pub(crate) n: usize,
-    pub(crate) step: usize,
+pin_project! {
+    #[must_use = "futures do nothing unless polled"]
+    pub struct YieldBy<C: Consumer> {
+        #[pin]
+        pub(crate) consumer: C,
+        pub(crate) step: usize,
+    }
 }
 
-impl<C> Unpin for YieldBy<C> where C: Consumer + Unpin {}
-
-impl<C: Consumer + Unpin> Future for YieldBy<C> {
+impl<C: Consumer> Future for YieldBy<C> {
     type Output = C::Output;
 
-    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        loop {
-            if self.n == self.step {
-                self.n = 0;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let mut budget = *this.step;
+        match this.consumer.poll_consume_n(cx, &mut budget) {
+            Poll::Ready(Some(ret)) => Poll::Ready(ret),
+            Poll::Ready(None) => {
                 cx.waker().wake_by_ref();
-                return Poll::Pending;
+                Poll::Pending
             }
-            match Pin::new(&mut self.consumer).poll_consume(cx) {
-                Poll::Ready(ret) => {
-                    if let Some(ret) = ret {
-                        return Poll::Ready(ret);
-                    }
-                }
-                Poll::Pending => {
-                    self.n = 0;
-                    return Poll::Pending;
-                }
-            }
-            self.n += 1;
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::{
+        consumer::ConsumerExt,
+        stream::{ext::StreamExt, IntoFusion},
+    };
+
+    #[test]
+    fn yield_by_pinned() {
+        // `async` blocks are `!Unpin`, and so are the consumers holding them.
+        for step in [1, 4] {
+            let sum = spin_on::spin_on(
+                (1..=4)
+                    .into_fusion()
+                    .map_async(|i| async move { i * 10 })
+                    .fold(0, |acc, i| acc + i)
+                    .yield_by(step),
+            );
+            assert_eq!(sum, 100);
         }
     }
 }
@@ -1,14 +1,21 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(feature = "nightly", feature(extend_one))]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub mod consumer;
 pub mod execution;
+pub mod rng;
 pub mod step;
 pub mod stream;
+pub mod time;
 
 pub mod prelude {
     pub use crate::{
         consumer::{Consumer, ConsumerExt},
-        stream::{ext::StreamExt, IntoFusion, IteratorStream, Stream},
+        stream::{
+            ext::StreamExt, DoubleEndedStream, ExactSizeStream, IntoFusion, IteratorStream, Stream,
+        },
     };
 }
@@ -1,3 +1,6 @@
+use core::ops::ControlFlow;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Step<T> {
     NotYet,
     Ready(T),
@@ -5,6 +8,133 @@ pub enum Step<T> {
 }
 
 impl<T> Step<T> {
+    #[inline]
+    pub const fn is_ready(&self) -> bool {
+        matches!(self, Step::Ready(_))
+    }
+
+    #[inline]
+    pub const fn is_not_yet(&self) -> bool {
+        matches!(self, Step::NotYet)
+    }
+
+    #[inline]
+    pub const fn is_done(&self) -> bool {
+        matches!(self, Step::Done)
+    }
+
+    /// Returns the item if the step is [`Step::Ready`].
+    #[inline]
+    pub fn ready(self) -> Option<T> {
+        match self {
+            Step::Ready(ready) => Some(ready),
+            _ => None,
+        }
+    }
+
+    /// Returns `None` for [`Step::NotYet`], and otherwise what [`Iterator::next()`] would return.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fusion_core::step::Step;
+    ///
+    /// assert_eq!(Step::<i32>::NotYet.into_option(), None);
+    /// assert_eq!(Step::Ready(1).into_option(), Some(Some(1)));
+    /// assert_eq!(Step::<i32>::Done.into_option(), Some(None));
+    /// ```
+    #[inline]
+    pub fn into_option(self) -> Option<Option<T>> {
+        match self {
+            Step::NotYet => None,
+            Step::Ready(ready) => Some(Some(ready)),
+            Step::Done => Some(None),
+        }
+    }
+
+    /// Returns the item if the step is [`Step::Ready`], and `default` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fusion_core::step::Step;
+    ///
+    /// assert_eq!(Step::Ready(1).unwrap_or(0), 1);
+    /// assert_eq!(Step::NotYet.unwrap_or(0), 0);
+    /// assert_eq!(Step::Done.unwrap_or(0), 0);
+    /// ```
+    #[inline]
+    pub fn unwrap_or(self, default: T) -> T {
+        match self {
+            Step::Ready(ready) => ready,
+            _ => default,
+        }
+    }
+
+    /// Turns a [`Step::Ready`] whose item does not match `predicate` into [`Step::NotYet`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fusion_core::step::Step;
+    ///
+    /// assert_eq!(Step::Ready(4).filter(|i| i % 2 == 0), Step::Ready(4));
+    /// assert_eq!(Step::Ready(3).filter(|i| i % 2 == 0), Step::NotYet);
+    /// assert_eq!(Step::Done.filter(|i: &i32| i % 2 == 0), Step::Done);
+    /// ```
+    #[inline]
+    pub fn filter<P>(self, predicate: P) -> Step<T>
+    where
+        P: FnOnce(&T) -> bool,
+    {
+        match self {
+            Step::Ready(ready) if !predicate(&ready) => Step::NotYet,
+            step => step,
+        }
+    }
+
+    /// Keeps a [`Step::Ready`], and calls `f` for anything else.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fusion_core::step::Step;
+    ///
+    /// assert_eq!(Step::Ready(1).or_else(|| Step::Ready(2)), Step::Ready(1));
+    /// assert_eq!(Step::NotYet.or_else(|| Step::Ready(2)), Step::Ready(2));
+    /// assert_eq!(Step::Done.or_else(|| Step::NotYet::<i32>), Step::NotYet);
+    /// ```
+    #[inline]
+    pub fn or_else<F>(self, f: F) -> Step<T>
+    where
+        F: FnOnce() -> Step<T>,
+    {
+        match self {
+            Step::Ready(ready) => Step::Ready(ready),
+            _ => f(),
+        }
+    }
+
+    /// Pairs two ready items. [`Step::Done`] on either side wins over [`Step::NotYet`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fusion_core::step::Step;
+    ///
+    /// assert_eq!(Step::Ready(1).zip(Step::Ready('a')), Step::Ready((1, 'a')));
+    /// assert_eq!(Step::Ready(1).zip(Step::<char>::NotYet), Step::NotYet);
+    /// assert_eq!(Step::<i32>::NotYet.zip(Step::<char>::Done), Step::Done);
+    /// ```
+    #[inline]
+    pub fn zip<U>(self, other: Step<U>) -> Step<(T, U)> {
+        match (self, other) {
+            (Step::Ready(a), Step::Ready(b)) => Step::Ready((a, b)),
+            (Step::Done, _) | (_, Step::Done) => Step::Done,
+            _ => Step::NotYet,
+        }
+    }
+
     #[inline]
     pub fn map<G, F>(self, mut f: F) -> Step<G>
     where
@@ -82,8 +212,100 @@ impl<T> Step<&T> {
     {
         match self {
             Step::NotYet => Step::NotYet,
-            Step::Ready(v) => Step::Ready(v.clone()),
+            Step::Ready(v) => Step::Ready(*v),
             Step::Done => Step::Done,
         }
     }
 }
+
+/// `Some` becomes [`Step::Ready`] and `None` becomes [`Step::Done`], like the end of an iterator.
+///
+/// ```
+/// use fusion_core::step::Step;
+///
+/// assert_eq!(Step::from(Some(1)), Step::Ready(1));
+/// assert_eq!(Step::<i32>::from(None), Step::Done);
+/// ```
+impl<T> From<Option<T>> for Step<T> {
+    #[inline]
+    fn from(option: Option<T>) -> Self {
+        match option {
+            Some(v) => Step::Ready(v),
+            None => Step::Done,
+        }
+    }
+}
+
+/// [`ControlFlow::Continue`] becomes [`Step::Ready`] and [`ControlFlow::Break`] becomes
+/// [`Step::Done`].
+///
+/// ```
+/// use core::ops::ControlFlow;
+///
+/// use fusion_core::step::Step;
+///
+/// assert_eq!(Step::from(ControlFlow::Continue(1)), Step::Ready(1));
+/// assert_eq!(Step::<i32>::from(ControlFlow::Break(())), Step::Done);
+/// ```
+impl<T> From<ControlFlow<(), T>> for Step<T> {
+    #[inline]
+    fn from(flow: ControlFlow<(), T>) -> Self {
+        match flow {
+            ControlFlow::Continue(v) => Step::Ready(v),
+            ControlFlow::Break(()) => Step::Done,
+        }
+    }
+}
+
+/// Extracts the item of a `Poll<Step<T>>`, returning early from the enclosing `poll_next` on
+/// anything else.
+///
+/// ```
+/// use core::{
+///     pin::Pin,
+///     task::{Context, Poll},
+/// };
+///
+/// use fusion_core::{prelude::*, ready_step, step::Step};
+///
+/// struct Double<S>(S);
+///
+/// impl<S: Stream<Item = i32> + Unpin> Stream for Double<S> {
+///     type Item = i32;
+///
+///     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<i32>> {
+///         let v = ready_step!(Pin::new(&mut self.0).poll_next(cx));
+///         Poll::Ready(Step::Ready(v * 2))
+///     }
+/// }
+///
+/// # spin_on::spin_on(async {
+/// let mut s = Double([1, 2].into_iter().into_fusion());
+/// assert_eq!(s.next().await, Step::Ready(2));
+/// assert_eq!(s.next().await, Step::Ready(4));
+/// assert_eq!(s.next().await, Step::Done);
+///
+/// let mut i = 0;
+/// let mut s = Double(fusion_core::stream::source::from_fn(move |_| {
+///     i += 1;
+///     Poll::Ready(if i == 1 { Step::NotYet } else { Step::Ready(i) })
+/// }));
+/// assert_eq!(s.next().await, Step::NotYet);
+/// assert_eq!(s.next().await, Step::Ready(4));
+/// # });
+/// ```
+#[macro_export]
+macro_rules! ready_step {
+    ($e:expr $(,)?) => {
+        match $e {
+            ::core::task::Poll::Ready($crate::step::Step::Ready(t)) => t,
+            ::core::task::Poll::Ready($crate::step::Step::NotYet) => {
+                return ::core::task::Poll::Ready($crate::step::Step::NotYet);
+            }
+            ::core::task::Poll::Ready($crate::step::Step::Done) => {
+                return ::core::task::Poll::Ready($crate::step::Step::Done);
+            }
+            ::core::task::Poll::Pending => return ::core::task::Poll::Pending,
+        }
+    };
+}
@@ -6,7 +6,7 @@ use core::{
 use futures_core::ready;
 use pin_project_lite::pin_project;
 
-use super::Stream;
+use super::{DoubleEndedStream, Stream};
 use crate::{step::Step, stream::fuse::Fuse};
 
 pin_project! {
@@ -22,6 +22,8 @@ pin_project! {
 impl<S: Stream, U: Stream<Item = S::Item>> Stream for Chain<S, U> {
     type Item = S::Item;
 
+    const SYNC: bool = S::SYNC && U::SYNC;
+
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
         let mut this = self.project();
 
@@ -34,7 +36,50 @@ impl<S: Stream, U: Stream<Item = S::Item>> Stream for Chain<S, U> {
         }
 
         if !this.second.done {
-            let next = ready!(this.first.as_mut().poll_next(cx));
+            let next = ready!(this.second.as_mut().poll_next(cx));
+            match next {
+                Step::Done => {}
+                next => return Poll::Ready(next),
+            }
+        }
+
+        if this.first.done && this.second.done {
+            Poll::Ready(Step::Done)
+        } else {
+            unreachable!()
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (first_lower, first_upper) = self.first.size_hint();
+        let (second_lower, second_upper) = self.second.size_hint();
+        let lower = first_lower.saturating_add(second_lower);
+        let upper = match (first_upper, second_upper) {
+            (Some(first), Some(second)) => first.checked_add(second),
+            _ => None,
+        };
+        (lower, upper)
+    }
+}
+
+impl<S, U> DoubleEndedStream for Chain<S, U>
+where
+    S: DoubleEndedStream,
+    U: DoubleEndedStream<Item = S::Item>,
+{
+    fn poll_next_back(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let mut this = self.project();
+
+        if !this.second.done {
+            let next = ready!(this.second.as_mut().poll_next_back(cx));
+            match next {
+                Step::Done => {}
+                next => return Poll::Ready(next),
+            }
+        }
+
+        if !this.first.done {
+            let next = ready!(this.first.as_mut().poll_next_back(cx));
             match next {
                 Step::Done => {}
                 next => return Poll::Ready(next),
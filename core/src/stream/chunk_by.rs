@@ -0,0 +1,162 @@
+use alloc::vec::Vec;
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::ready;
+use pin_project_lite::pin_project;
+
+use super::Stream;
+use crate::step::Step;
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct ChunkBy<S: Stream, F, K> {
+        #[pin]
+        pub(super) stream: S,
+        pub(super) key_fn: F,
+        pub(super) chunk: Option<(K, Vec<S::Item>)>,
+        pub(super) done: bool,
+    }
+}
+
+impl<S, F, K> Stream for ChunkBy<S, F, K>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> K,
+    K: PartialEq,
+{
+    type Item = (K, Vec<S::Item>);
+
+    const SYNC: bool = S::SYNC;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let this = self.project();
+
+        if *this.done {
+            return Poll::Ready(Step::Done);
+        }
+        Poll::Ready(match ready!(this.stream.poll_next(cx)) {
+            Step::NotYet => Step::NotYet,
+            Step::Ready(item) => {
+                let key = (this.key_fn)(&item);
+                match this.chunk {
+                    Some((current, items)) if *current == key => {
+                        items.push(item);
+                        Step::NotYet
+                    }
+                    chunk => match chunk.replace((key, alloc::vec![item])) {
+                        Some(chunk) => Step::Ready(chunk),
+                        None => Step::NotYet,
+                    },
+                }
+            }
+            Step::Done => {
+                *this.done = true;
+                this.chunk.take().into()
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let chunk = self.chunk.is_some() as usize;
+        let (lower, upper) = self.stream.size_hint();
+        (
+            (lower > 0 || chunk > 0) as usize,
+            upper.and_then(|upper| upper.checked_add(chunk)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use core::{
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+    use std::vec::Vec;
+
+    use crate::{
+        step::Step,
+        stream::{
+            ext::StreamExt,
+            tests::{assert_size_hints, drain},
+            IntoFusion, Stream,
+        },
+    };
+
+    #[test]
+    fn chunk_by() {
+        let words = [
+            "apple",
+            "avocado",
+            "banana",
+            "blueberry",
+            "cherry",
+            "apricot",
+        ];
+        fn chunks<'a>(
+            words: &'a [&'static str],
+        ) -> impl Iterator<Item = (u8, Vec<&'static str>)> + Clone + 'a {
+            words
+                .chunk_by(|a, b| a.as_bytes()[0] == b.as_bytes()[0])
+                .map(|chunk| (chunk[0].as_bytes()[0], chunk.to_vec()))
+        }
+
+        assert_size_hints(
+            words
+                .into_iter()
+                .into_fusion()
+                .chunk_by(|w| w.as_bytes()[0]),
+            chunks(&words),
+        );
+        assert_eq!(
+            drain(
+                words
+                    .into_iter()
+                    .into_fusion()
+                    .filter(|w| w.len() > 5)
+                    .chunk_by(|w| w.as_bytes()[0])
+            ),
+            chunks(&["avocado", "banana", "blueberry", "cherry", "apricot"]).collect::<Vec<_>>()
+        );
+        assert!(drain((0..0).into_fusion().chunk_by(|i| *i)).is_empty());
+
+        let mut groups = pin!(words
+            .into_iter()
+            .into_fusion()
+            .group_by(|w| w.as_bytes()[0]));
+        let mut next_group = || loop {
+            match groups
+                .as_mut()
+                .poll_next(&mut Context::from_waker(Waker::noop()))
+            {
+                Poll::Ready(Step::Ready(group)) => break Some(group),
+                Poll::Ready(Step::NotYet) => {}
+                Poll::Ready(Step::Done) => break None,
+                Poll::Pending => unreachable!(),
+            }
+        };
+        let (key, apples) = next_group().unwrap();
+        assert_eq!((key, drain(apples)), (b'a', std::vec!["apple", "avocado"]));
+        // Skipping a group drops what is left of it.
+        let (key, mut berries) = next_group().unwrap();
+        assert_eq!(key, b'b');
+        assert!(matches!(
+            berries.poll_next(&mut Context::from_waker(Waker::noop())),
+            Poll::Ready(Step::Ready("banana"))
+        ));
+        let (key, cherries) = next_group().unwrap();
+        assert_eq!(drain(berries), [] as [&str; 0]);
+        assert_eq!((key, drain(cherries)), (b'c', std::vec!["cherry"]));
+        let (key, _) = next_group().unwrap();
+        assert_eq!(key, b'a');
+        assert!(next_group().is_none());
+    }
+}
@@ -6,7 +6,7 @@ use core::{
 use futures_core::ready;
 use pin_project_lite::pin_project;
 
-use super::Stream;
+use super::{DoubleEndedStream, ExactSizeStream, Stream};
 use crate::step::Step;
 
 pin_project! {
@@ -24,9 +24,34 @@ where
 {
     type Item = T;
 
+    const SYNC: bool = S::SYNC;
+
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
         let this = self.project();
         let next = ready!(this.stream.poll_next(cx));
         Poll::Ready(next.cloned())
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<'a, S, T: 'a> ExactSizeStream for Cloned<S>
+where
+    S: ExactSizeStream<Item = &'a T>,
+    T: Clone,
+{
+}
+
+impl<'a, S, T: 'a> DoubleEndedStream for Cloned<S>
+where
+    S: DoubleEndedStream<Item = &'a T>,
+    T: Clone,
+{
+    fn poll_next_back(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let this = self.project();
+        let next = ready!(this.stream.poll_next_back(cx));
+        Poll::Ready(next.cloned())
+    }
 }
@@ -0,0 +1,250 @@
+use core::{
+    ops::ControlFlow,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::ready;
+
+use super::Stream;
+use crate::step::Step;
+
+/// Folds `stream` like [`Stream::poll_fold_n()`], `f` returning `None` for the items it drops.
+///
+/// Runs of up to `limit` dropped items take a single step of `budget` between them, `limit` being
+/// capped by `budget` itself. With a `limit` of `1`, every dropped item takes a step, as if each of
+/// them surfaced a [`Step::NotYet`].
+#[inline]
+pub(crate) fn poll_fold_n<S, B, F>(
+    mut stream: Pin<&mut S>,
+    cx: &mut Context<'_>,
+    budget: &mut usize,
+    limit: usize,
+    acc: &mut B,
+    mut f: F,
+) -> Poll<Step<()>>
+where
+    S: Stream + ?Sized,
+    F: FnMut(&mut B, S::Item) -> Option<ControlFlow<()>>,
+{
+    let limit = limit.min(*budget);
+    if limit <= 1 {
+        return stream.poll_fold_n(cx, budget, acc, |acc, item| {
+            f(acc, item).unwrap_or(ControlFlow::Continue(()))
+        });
+    }
+    let mut run = 0;
+    loop {
+        // Steps handed back for the dropped items `stream` charged, but the run did not.
+        let mut refund = 0;
+        let next = stream
+            .as_mut()
+            .poll_fold_n(cx, budget, acc, |acc, item| match f(acc, item) {
+                Some(flow) => {
+                    run = 0;
+                    flow
+                }
+                None => {
+                    run += 1;
+                    if run == limit {
+                        run = 0;
+                    } else {
+                        refund += 1;
+                    }
+                    ControlFlow::Continue(())
+                }
+            });
+        *budget += refund;
+        match ready!(next) {
+            Step::NotYet if refund > 0 => {}
+            next => return Poll::Ready(next),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use core::{
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+    use std::vec::Vec;
+
+    use crate::{
+        consumer::ConsumerExt,
+        step::Step,
+        stream::{
+            ext::StreamExt,
+            tests::{polled, steps},
+            IntoFusion, Stream,
+        },
+    };
+
+    #[test]
+    fn coalesce() {
+        use core::ops::ControlFlow;
+
+        /// Drains `stream` through `poll_fold_n()`, `budget` steps at a time, returning its items
+        /// and the number of calls it took.
+        fn fold_calls<S: Stream<Item = i32>>(stream: S, budget: usize) -> (Vec<i32>, usize) {
+            let mut stream = pin!(stream);
+            let mut cx = Context::from_waker(Waker::noop());
+            let (mut items, mut calls) = (Vec::new(), 0);
+            loop {
+                calls += 1;
+                let mut left = budget;
+                let next =
+                    stream
+                        .as_mut()
+                        .poll_fold_n(&mut cx, &mut left, &mut items, |items, item| {
+                            items.push(item);
+                            ControlFlow::Continue(())
+                        });
+                match next {
+                    Poll::Ready(Step::NotYet) => assert_eq!(left, 0),
+                    Poll::Ready(Step::Done) => break (items, calls),
+                    _ => unreachable!(),
+                }
+            }
+        }
+
+        // Dropped items are stepped over, but by default each one is charged to the budget.
+        let (items, calls) = fold_calls((0..100).into_fusion().filter(|i| i % 10 == 0), 64);
+        assert_eq!((items, calls), ((0..100).step_by(10).collect(), 2));
+        let (items, calls) = fold_calls((0..100).into_fusion().skip(90), 16);
+        assert_eq!((items, calls), ((90..100).collect(), 7));
+        let (items, calls) = fold_calls((0..100).into_fusion().step_by(10), 4);
+        assert_eq!((items, calls), ((0..100).step_by(10).collect(), 26));
+        let (items, calls) = fold_calls((0..100).into_fusion().skip_while(|i| *i < 50), 1);
+        assert_eq!((items, calls), ((50..100).collect(), 101));
+        let (items, calls) = fold_calls((0..100).into_fusion().skip_while(|i| *i < 50), usize::MAX);
+        assert_eq!((items, calls), ((50..100).collect(), 1));
+
+        // A `Step::NotYet` from upstream takes a step too.
+        let mut i = 0;
+        let hiccups = crate::stream::source::from_fn(move |_| {
+            i += 1;
+            Poll::Ready(match i {
+                21.. => Step::Done,
+                i if i % 2 == 0 => Step::Ready(i / 2),
+                _ => Step::NotYet,
+            })
+        });
+        let (items, calls) = fold_calls(hiccups.filter(|i| i % 2 == 0), 4);
+        assert_eq!((items, calls), (std::vec![2, 4, 6, 8, 10], 6));
+
+        // Coalesced, a run of dropped items takes a single step, runs being capped by the budget.
+        let (items, calls) = fold_calls(
+            (0..100).into_fusion().filter(|i| i % 10 == 0).coalesce(100),
+            4,
+        );
+        assert_eq!((items, calls), ((0..100).step_by(10).collect(), 8));
+        let (items, calls) = fold_calls((0..100).into_fusion().skip(90).coalesce(8), 16);
+        assert_eq!((items, calls), ((90..100).collect(), 2));
+        let (items, calls) = fold_calls((0..100).into_fusion().step_by(10).coalesce(9), 10);
+        assert_eq!((items, calls), ((0..100).step_by(10).collect(), 3));
+        let (items, calls) = fold_calls(
+            (0..100).into_fusion().skip_while(|i| *i < 50).coalesce(50),
+            1,
+        );
+        assert_eq!((items, calls), ((50..100).collect(), 101));
+        let (items, calls) = fold_calls(
+            (0..100).into_fusion().skip_while(|i| *i < 50).coalesce(50),
+            10,
+        );
+        assert_eq!((items, calls), ((50..100).collect(), 6));
+
+        // `NotYet` from upstream is never coalesced.
+        let mut i = 0;
+        let hiccups = crate::stream::source::from_fn(move |_| {
+            i += 1;
+            Poll::Ready(match i {
+                21.. => Step::Done,
+                i if i % 2 == 0 => Step::Ready(i / 2),
+                _ => Step::NotYet,
+            })
+        });
+        let (items, calls) = fold_calls(hiccups.filter(|i| i % 2 == 0).coalesce(64), 4);
+        assert_eq!((items, calls), (std::vec![2, 4, 6, 8, 10], 4));
+
+        // Polled item by item, a run of dropped items surfaces a single `NotYet`.
+        let not_yets = |steps: &[Step<i32>]| steps.iter().filter(|s| **s == Step::NotYet).count();
+        let filter = (0..1000).into_fusion().filter(|i| i % 100 == 0);
+        assert_eq!(not_yets(&steps(filter)), 990);
+        let filter = (0..1000)
+            .into_fusion()
+            .filter(|i| i % 100 == 0)
+            .coalesce(32);
+        let filtered = steps(filter);
+        assert_eq!(not_yets(&filtered), 30);
+        assert_eq!(
+            filtered
+                .into_iter()
+                .filter_map(Step::ready)
+                .collect::<Vec<_>>(),
+            (0..1000).step_by(100).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            not_yets(&steps((0..1000).into_fusion().skip(990).coalesce(100))),
+            9
+        );
+        assert_eq!(
+            not_yets(&steps((0..1000).into_fusion().step_by(100).coalesce(100))),
+            0
+        );
+        assert_eq!(
+            not_yets(&steps(
+                (0..1000)
+                    .into_fusion()
+                    .skip_while(|i| *i < 990)
+                    .coalesce(500)
+            )),
+            1
+        );
+        // The 10 `NotYet`s of upstream still surface, every rejected item being stepped over.
+        let mut i = 0;
+        let hiccups = crate::stream::source::from_fn(move |_| {
+            i += 1;
+            Poll::Ready(match i {
+                21.. => Step::Done,
+                i if i % 2 == 0 => Step::Ready(i / 2),
+                _ => Step::NotYet,
+            })
+        });
+        assert_eq!(not_yets(&steps(hiccups.filter(|_| false).coalesce(64))), 10);
+
+        // A mostly rejecting filter yields back to the executor less often.
+        fn yields<C: crate::consumer::Consumer>(consumer: C) -> (C::Output, usize) {
+            use core::future::Future;
+
+            let mut consumer = pin!(consumer.yield_by(64));
+            let mut cx = Context::from_waker(Waker::noop());
+            let mut yields = 0;
+            loop {
+                match consumer.as_mut().poll(&mut cx) {
+                    Poll::Ready(output) => break (output, yields),
+                    Poll::Pending => yields += 1,
+                }
+            }
+        }
+        let (count, baseline) = yields((0..10_000).into_fusion().filter(|i| i % 100 == 0).count());
+        assert_eq!((count, baseline), (100, 156));
+        let (count, coalesced) = yields(
+            (0..10_000)
+                .into_fusion()
+                .filter(|i| i % 100 == 0)
+                .coalesce(usize::MAX)
+                .count(),
+        );
+        assert_eq!((count, coalesced), (100, 3));
+        let (count, coalesced) = yields(
+            polled(10_000)
+                .filter(|i| i % 100 == 0)
+                .coalesce(usize::MAX)
+                .count(),
+        );
+        assert_eq!((count, coalesced), (100, 3));
+    }
+}
@@ -6,14 +6,14 @@ use core::{
 use futures_core::ready;
 use pin_project_lite::pin_project;
 
-use super::Stream;
+use super::{DoubleEndedStream, ExactSizeStream, Stream};
 use crate::step::Step;
 
 pin_project! {
     #[must_use = "streams do nothing unless polled"]
     pub struct Copied<S> {
         #[pin]
-        stream: S,
+        pub(super) stream: S,
     }
 }
 
@@ -24,9 +24,34 @@ where
 {
     type Item = T;
 
+    const SYNC: bool = S::SYNC;
+
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
         let this = self.project();
         let next = ready!(this.stream.poll_next(cx));
         Poll::Ready(next.copied())
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<'a, S, T: 'a> ExactSizeStream for Copied<S>
+where
+    S: ExactSizeStream<Item = &'a T>,
+    T: Copy,
+{
+}
+
+impl<'a, S, T: 'a> DoubleEndedStream for Copied<S>
+where
+    S: DoubleEndedStream<Item = &'a T>,
+    T: Copy,
+{
+    fn poll_next_back(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let this = self.project();
+        let next = ready!(this.stream.poll_next_back(cx));
+        Poll::Ready(next.copied())
+    }
 }
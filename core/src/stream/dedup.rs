@@ -0,0 +1,154 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::ready;
+use pin_project_lite::pin_project;
+
+use super::{fuse::Fuse, Stream};
+use crate::step::Step;
+
+/// The stream returned by [`dedup()`][`super::ext::StreamExt::dedup()`].
+pub type Dedup<S> = DedupBy<S, fn(&<S as Stream>::Item, &<S as Stream>::Item) -> bool>;
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct DedupBy<S: Stream, F> {
+        #[pin]
+        pub(super) stream: Fuse<S>,
+        pub(super) same: F,
+        // The first item of the current run, yielded once the run ends.
+        pub(super) last: Option<S::Item>,
+    }
+}
+
+impl<S, F> Stream for DedupBy<S, F>
+where
+    S: Stream,
+    F: FnMut(&S::Item, &S::Item) -> bool,
+{
+    type Item = S::Item;
+
+    const SYNC: bool = S::SYNC;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let this = self.project();
+        Poll::Ready(match ready!(this.stream.poll_next(cx)) {
+            Step::NotYet => Step::NotYet,
+            Step::Ready(item) => match this.last {
+                Some(last) if (this.same)(last, &item) => Step::NotYet,
+                _ => this.last.replace(item).map_or(Step::NotYet, Step::Ready),
+            },
+            Step::Done => this.last.take().map_or(Step::Done, Step::Ready),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let last = self.last.is_some() as usize;
+        let (lower, upper) = self.stream.size_hint();
+        (
+            (last > 0 || lower > 0) as usize,
+            upper.and_then(|upper| upper.checked_add(last)),
+        )
+    }
+}
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct DedupByKey<S, F, K> {
+        #[pin]
+        pub(super) stream: S,
+        pub(super) key_fn: F,
+        pub(super) last: Option<K>,
+    }
+}
+
+impl<S, F, K> Stream for DedupByKey<S, F, K>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> K,
+    K: PartialEq,
+{
+    type Item = S::Item;
+
+    const SYNC: bool = S::SYNC;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let this = self.project();
+        Poll::Ready(match ready!(this.stream.poll_next(cx)) {
+            Step::NotYet => Step::NotYet,
+            Step::Ready(item) => {
+                let key = (this.key_fn)(&item);
+                if this.last.as_ref() == Some(&key) {
+                    Step::NotYet
+                } else {
+                    *this.last = Some(key);
+                    Step::Ready(item)
+                }
+            }
+            Step::Done => Step::Done,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+        ((self.last.is_none() && lower > 0) as usize, upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use core::fmt::Debug;
+
+    use crate::{
+        consumer::ConsumerExt,
+        stream::{ext::StreamExt, tests::drain, IntoFusion, Stream},
+    };
+
+    #[test]
+    fn dedup() {
+        let items = [1, 1, 2, 3, 3, 3, 1, 4, 4];
+        assert_eq!(
+            drain(items.into_iter().into_fusion().dedup()),
+            [1, 2, 3, 1, 4]
+        );
+        assert_eq!(
+            drain(items.into_iter().into_fusion().dedup_by_key(|i| i % 2)),
+            [1, 2, 3, 4]
+        );
+        assert_eq!(
+            drain(items.into_iter().into_fusion().dedup_by(|a, b| b - a == 1)),
+            [1, 1, 3, 3, 3, 1, 4, 4]
+        );
+        assert_eq!(
+            items.into_iter().into_fusion().dedup().size_hint(),
+            (1, Some(items.len()))
+        );
+        assert_eq!(drain(core::iter::empty::<i32>().into_fusion().dedup()), []);
+
+        // Items need not be `Clone`.
+        #[derive(Debug, PartialEq)]
+        struct Token(u8);
+        assert_eq!(
+            drain([0, 0, 1].map(Token).into_iter().into_fusion().dedup()),
+            [Token(0), Token(1)]
+        );
+
+        // Every dropped duplicate is one step, like a rejection of `filter`.
+        let count = |step| {
+            spin_on::spin_on(
+                (0..1000)
+                    .into_fusion()
+                    .map(|i| i / 10)
+                    .dedup()
+                    .count()
+                    .yield_by(step),
+            )
+        };
+        assert_eq!(count(1), 100);
+        assert_eq!(count(7), 100);
+    }
+}
@@ -1,19 +1,69 @@
 use core::{
+    cmp::Ordering,
     future::Future,
+    ops::ControlFlow,
     pin::Pin,
     task::{Context, Poll},
+    time::Duration,
 };
 
 use super::{
-    chain::Chain, cloned::Cloned, filter::Filter, flat_map::FlatMap, flatten::Flatten, fuse::Fuse,
-    map::Map, map_async::MapAsync, next::NextFuture, skip::Skip, skip_while::SkipWhile,
-    step_by::StepBy, take::Take, take_while::TakeWhile, try_next::TryNextFuture, Stream,
+    chain::Chain,
+    cloned::Cloned,
+    copied::Copied,
+    dedup::{Dedup, DedupBy, DedupByKey},
+    filter::Filter,
+    flat_map::FlatMap,
+    flatten::Flatten,
+    fuse::Fuse,
+    interleave::{Interleave, InterleaveShortest},
+    map::Map,
+    map_async::MapAsync,
+    merge_sorted::MergeSortedBy,
+    next::NextFuture,
+    rate_limit::{RateLimit, Throttle},
+    rev::Rev,
+    sample::SampleBernoulli,
+    skip::Skip,
+    skip_while::SkipWhile,
+    step_by::StepBy,
+    take::Take,
+    take_while::TakeWhile,
+    try_next::TryNextFuture,
+    watermark::WithWatermarks,
+    DoubleEndedStream, Stream,
 };
+#[cfg(feature = "alloc")]
+use super::{
+    chunk_by::ChunkBy,
+    group_by::GroupBy,
+    keyed_process::KeyedProcess,
+    unique::{Unique, UniqueBy},
+    watermark::Event,
+    window::{Events, SessionWindow, SlidingWindow, TumblingWindow, Windows},
+};
+#[cfg(feature = "alloc")]
+use crate::consumer::stats::{Histogram, HyperLogLog, Quantiles, Reservoir, TopK};
 use crate::{
     consumer::{
-        collect::Collect, count::Count, fold::Fold, partition::Partition, try_collect::TryCollect,
+        collect::Collect,
+        count::Count,
+        find::Find,
+        fold::Fold,
+        fold_while::FoldWhile,
+        last::{Last, LastBack},
+        partition::Partition,
+        partition_result::PartitionResult,
+        stats::{Aggregate, Aggregator, AsF64, MinMax, Moments, Running},
+        try_collect::TryCollect,
+        try_count::TryCount,
+        try_fold::{Try, TryFold},
+        try_partition::TryPartition,
+        try_reduce::TryReduce,
     },
+    rng::Rng,
     step::Step,
+    time::Timer,
 };
 
 /// Extension trait for [`Stream`].
@@ -28,21 +78,21 @@ pub trait StreamExt: Stream {
 
     /// Retrieves the next item in the stream.
     ///
-    /// Returns [`None`] when iteration is finished. Stream implementations may choose to or not to
-    /// resume iteration after that.
+    /// Returns [`Step::Done`] when iteration is finished. Stream implementations may choose to or
+    /// not to resume iteration after that.
     ///
     /// # Examples
     ///
     /// ```
-    /// use futures_lite::stream::{self, StreamExt};
+    /// use fusion_core::{prelude::*, step::Step};
     ///
     /// # spin_on::spin_on(async {
-    /// let mut s = stream::iter(1..=3);
+    /// let mut s = (1..=3).into_fusion();
     ///
-    /// assert_eq!(s.next().await, Some(1));
-    /// assert_eq!(s.next().await, Some(2));
-    /// assert_eq!(s.next().await, Some(3));
-    /// assert_eq!(s.next().await, None);
+    /// assert!(matches!(s.next().await, Step::Ready(1)));
+    /// assert!(matches!(s.next().await, Step::Ready(2)));
+    /// assert!(matches!(s.next().await, Step::Ready(3)));
+    /// assert!(matches!(s.next().await, Step::Done));
     /// # });
     /// ```
     fn next(&mut self) -> NextFuture<'_, Self>
@@ -55,22 +105,22 @@ pub trait StreamExt: Stream {
     /// Retrieves the next item in the stream.
     ///
     /// This is similar to the [`next()`][`StreamExt::next()`] method, but returns
-    /// `Result<Option<T>, E>` rather than `Option<Result<T, E>>`.
+    /// `Result<Step<T>, E>` rather than `Step<Result<T, E>>`.
     ///
     /// Note that `s.try_next().await` is equivalent to `s.next().await.transpose()`.
     ///
     /// # Examples
     ///
     /// ```
-    /// use futures_lite::stream::{self, StreamExt};
+    /// use fusion_core::{prelude::*, step::Step};
     ///
     /// # spin_on::spin_on(async {
-    /// let mut s = stream::iter(vec![Ok(1), Ok(2), Err("error")]);
+    /// let mut s = vec![Ok(1), Ok(2), Err("error")].into_iter().into_fusion();
     ///
-    /// assert_eq!(s.try_next().await, Ok(Some(1)));
-    /// assert_eq!(s.try_next().await, Ok(Some(2)));
-    /// assert_eq!(s.try_next().await, Err("error"));
-    /// assert_eq!(s.try_next().await, Ok(None));
+    /// assert!(matches!(s.try_next().await, Ok(Step::Ready(1))));
+    /// assert!(matches!(s.try_next().await, Ok(Step::Ready(2))));
+    /// assert!(matches!(s.try_next().await, Err("error")));
+    /// assert!(matches!(s.try_next().await, Ok(Step::Done)));
     /// # });
     /// ```
     fn try_next<T, E>(&mut self) -> TryNextFuture<'_, Self>
@@ -90,6 +140,18 @@ pub trait StreamExt: Stream {
         }
     }
 
+    /// Counts the `Ok` items of the stream, stopping at the first `Err` and returning it together
+    /// with the number of items counted so far.
+    fn try_count<T, E>(self) -> TryCount<Self>
+    where
+        Self: Stream<Item = Result<T, E>> + Sized,
+    {
+        TryCount {
+            stream: self,
+            count: 0,
+        }
+    }
+
     fn map<T, F>(self, f: F) -> Map<Self, F>
     where
         Self: Sized,
@@ -142,6 +204,7 @@ pub trait StreamExt: Stream {
         Filter {
             stream: self,
             predicate,
+            coalesce: 1,
         }
     }
 
@@ -160,6 +223,7 @@ pub trait StreamExt: Stream {
         TakeWhile {
             stream: self,
             predicate,
+            done: false,
         }
     }
 
@@ -167,7 +231,11 @@ pub trait StreamExt: Stream {
     where
         Self: Sized,
     {
-        Skip { stream: self, n }
+        Skip {
+            stream: self,
+            n,
+            coalesce: 1,
+        }
     }
 
     fn skip_while<P>(self, predicate: P) -> SkipWhile<Self, P>
@@ -178,6 +246,7 @@ pub trait StreamExt: Stream {
         SkipWhile {
             stream: self,
             predicate: Some(predicate),
+            coalesce: 1,
         }
     }
 
@@ -190,6 +259,7 @@ pub trait StreamExt: Stream {
             stream: self,
             step,
             i: 0,
+            coalesce: 1,
         }
     }
 
@@ -214,6 +284,408 @@ pub trait StreamExt: Stream {
         }
     }
 
+    /// Drops items equal to the item right before them.
+    fn dedup(self) -> Dedup<Self>
+    where
+        Self: Sized,
+        Self::Item: PartialEq,
+    {
+        self.dedup_by(PartialEq::eq)
+    }
+
+    /// Drops items for which `same(previous, item)` holds, `previous` being the first item of the
+    /// current run.
+    ///
+    /// An item is only yielded once the next distinct item comes, or the stream is done.
+    fn dedup_by<F>(self, same: F) -> DedupBy<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> bool,
+    {
+        DedupBy {
+            stream: self.fuse(),
+            same,
+            last: None,
+        }
+    }
+
+    /// Drops items whose key is equal to the key of the item right before them.
+    fn dedup_by_key<K, F>(self, key_fn: F) -> DedupByKey<Self, F, K>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> K,
+        K: PartialEq,
+    {
+        DedupByKey {
+            stream: self,
+            key_fn,
+            last: None,
+        }
+    }
+
+    /// Drops items equal to any item before them.
+    #[cfg(feature = "alloc")]
+    fn unique(self) -> Unique<Self>
+    where
+        Self: Sized,
+        Self::Item: Eq + core::hash::Hash + Clone,
+    {
+        self.unique_by(Clone::clone)
+    }
+
+    /// Drops items whose key is equal to the key of any item before them.
+    #[cfg(feature = "alloc")]
+    fn unique_by<K, F>(self, key_fn: F) -> UniqueBy<Self, F, K>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> K,
+        K: Eq + core::hash::Hash,
+    {
+        UniqueBy {
+            stream: self,
+            key_fn,
+            seen: hashbrown::HashSet::new(),
+        }
+    }
+
+    /// Collects runs of consecutive items with equal keys, yielding each run with its key.
+    ///
+    /// A run is only yielded once the first item of the next one, or the end of the stream, has
+    /// been seen.
+    #[cfg(feature = "alloc")]
+    fn chunk_by<K, F>(self, key_fn: F) -> ChunkBy<Self, F, K>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> K,
+        K: PartialEq,
+    {
+        ChunkBy {
+            stream: self,
+            key_fn,
+            chunk: None,
+            done: false,
+        }
+    }
+
+    /// Splits the stream into runs of consecutive items with equal keys, yielding each run as a
+    /// [`Group`][`super::group_by::Group`] stream along with its key.
+    ///
+    /// Groups share the underlying stream, so each group has to be consumed before polling for the
+    /// next one. Items of a group that is skipped are dropped.
+    #[cfg(feature = "alloc")]
+    fn group_by<K, F>(self, key_fn: F) -> GroupBy<Self, F, K>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> K,
+        K: PartialEq + Clone,
+    {
+        GroupBy::new(self, key_fn)
+    }
+
+    /// Runs `f` over every item along with the state of its key, as computed by `key_fn`,
+    /// yielding everything `f` returns.
+    ///
+    /// The state of a key starts out as returned by `init`. An item for which `f` returns nothing
+    /// yields [`Step::NotYet`]. See [`ttl()`][`KeyedProcess::ttl()`] to drop the state of idle
+    /// keys.
+    ///
+    /// ```
+    /// use fusion_core::prelude::*;
+    ///
+    /// // Running totals per parity, emitted every other item of each.
+    /// let totals = (1..=8).into_fusion().keyed_process(
+    ///     |i| i % 2,
+    ///     || (0, 0),
+    ///     |(count, sum), i| {
+    ///         *count += 1;
+    ///         *sum += i;
+    ///         (*count % 2 == 0).then_some(*sum)
+    ///     },
+    /// );
+    /// let totals: Vec<i32> = spin_on::spin_on(totals.collect().yield_by(32));
+    /// assert_eq!(totals, [4, 6, 16, 20]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn keyed_process<K, T, I, KF, IF, F>(
+        self,
+        key_fn: KF,
+        init: IF,
+        f: F,
+    ) -> KeyedProcess<Self, K, T, I, KF, IF, F>
+    where
+        Self: Sized,
+        K: Eq + core::hash::Hash + Clone,
+        I: IntoIterator,
+        KF: FnMut(&Self::Item) -> K,
+        IF: FnMut() -> T,
+        F: FnMut(&mut T, Self::Item) -> I,
+    {
+        KeyedProcess {
+            stream: self,
+            key_fn,
+            init,
+            f,
+            states: hashbrown::HashMap::new(),
+            outputs: None,
+            tick: 0,
+            ttl: None,
+            expiry: alloc::collections::BTreeMap::new(),
+        }
+    }
+
+    /// Groups items into consecutive, non-overlapping windows of `size` by the event time
+    /// `ts_fn` extracts from them.
+    ///
+    /// Windows cover `start..start + size` for every `start` that is a multiple of `size`. A
+    /// window is emitted once an item at or past its end is seen, see
+    /// [`allowed_lateness()`][`TumblingWindow::allowed_lateness()`] to wait longer. All open
+    /// windows are emitted, in order, when the stream is done.
+    #[cfg(feature = "alloc")]
+    fn window_tumbling<F>(self, size: u64, ts_fn: F) -> TumblingWindow<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> u64,
+    {
+        assert!(size > 0, "`size` must be greater than zero");
+        TumblingWindow {
+            stream: self.fuse(),
+            ts_fn,
+            size,
+            windows: Windows::new(),
+        }
+    }
+
+    /// Like [`window_tumbling()`][`StreamExt::window_tumbling()`], but windows start every
+    /// `slide` and may overlap, so an item can be part of several windows.
+    #[cfg(feature = "alloc")]
+    fn window_sliding<F>(self, size: u64, slide: u64, ts_fn: F) -> SlidingWindow<Self, F>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        F: FnMut(&Self::Item) -> u64,
+    {
+        assert!(size > 0, "`size` must be greater than zero");
+        assert!(slide > 0, "`slide` must be greater than zero");
+        SlidingWindow {
+            stream: self.fuse(),
+            ts_fn,
+            size,
+            slide,
+            windows: Windows::new(),
+        }
+    }
+
+    /// Groups items into sessions, each ending once no item has been seen for `gap` of event
+    /// time.
+    ///
+    /// A session covers `first..last + gap` for its first and last items. It is emitted like the
+    /// windows of [`window_tumbling()`][`StreamExt::window_tumbling()`].
+    #[cfg(feature = "alloc")]
+    fn window_session<F>(self, gap: u64, ts_fn: F) -> SessionWindow<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> u64,
+    {
+        assert!(gap > 0, "`gap` must be greater than zero");
+        SessionWindow {
+            stream: self.fuse(),
+            ts_fn,
+            gap,
+            windows: Windows::new(),
+        }
+    }
+
+    /// Like [`window_tumbling()`][`StreamExt::window_tumbling()`], but windows are closed by the
+    /// watermarks of the stream rather than by the timestamps of its items.
+    ///
+    /// Items at or past a watermark are still assigned to their windows, so out of order items
+    /// only get dropped once a watermark has overtaken them.
+    #[cfg(feature = "alloc")]
+    fn event_window_tumbling<T, F>(self, size: u64, ts_fn: F) -> TumblingWindow<Self, F, Events>
+    where
+        Self: Stream<Item = Event<T>> + Sized,
+        F: FnMut(&T) -> u64,
+    {
+        assert!(size > 0, "`size` must be greater than zero");
+        TumblingWindow {
+            stream: self.fuse(),
+            ts_fn,
+            size,
+            windows: Windows::new(),
+        }
+    }
+
+    /// Like [`window_sliding()`][`StreamExt::window_sliding()`], but windows are closed by
+    /// watermarks, see [`event_window_tumbling()`][`StreamExt::event_window_tumbling()`].
+    #[cfg(feature = "alloc")]
+    fn event_window_sliding<T, F>(
+        self,
+        size: u64,
+        slide: u64,
+        ts_fn: F,
+    ) -> SlidingWindow<Self, F, Events>
+    where
+        Self: Stream<Item = Event<T>> + Sized,
+        T: Clone,
+        F: FnMut(&T) -> u64,
+    {
+        assert!(size > 0, "`size` must be greater than zero");
+        assert!(slide > 0, "`slide` must be greater than zero");
+        SlidingWindow {
+            stream: self.fuse(),
+            ts_fn,
+            size,
+            slide,
+            windows: Windows::new(),
+        }
+    }
+
+    /// Like [`window_session()`][`StreamExt::window_session()`], but sessions are closed by
+    /// watermarks, see [`event_window_tumbling()`][`StreamExt::event_window_tumbling()`].
+    #[cfg(feature = "alloc")]
+    fn event_window_session<T, F>(self, gap: u64, ts_fn: F) -> SessionWindow<Self, F, Events>
+    where
+        Self: Stream<Item = Event<T>> + Sized,
+        F: FnMut(&T) -> u64,
+    {
+        assert!(gap > 0, "`gap` must be greater than zero");
+        SessionWindow {
+            stream: self.fuse(),
+            ts_fn,
+            gap,
+            windows: Windows::new(),
+        }
+    }
+
+    /// Wraps items in [`Event::Item`][`super::watermark::Event::Item`] and follows each one that
+    /// advances the event time with an [`Event::Watermark`][`super::watermark::Event::Watermark`]
+    /// trailing the largest timestamp seen by `max_out_of_orderness`.
+    ///
+    /// Items are expected to arrive at most `max_out_of_orderness` behind the latest one. A final
+    /// watermark of [`u64::MAX`] is emitted once the stream is done.
+    fn with_watermarks<F>(self, max_out_of_orderness: u64, ts_fn: F) -> WithWatermarks<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> u64,
+    {
+        WithWatermarks {
+            stream: self.fuse(),
+            ts_fn,
+            max_out_of_orderness,
+            watermark: None,
+            pending: false,
+        }
+    }
+
+    /// Keeps every item with a probability of `p`, independently of the others. Dropped items
+    /// yield [`Step::NotYet`].
+    fn sample_bernoulli<R>(self, p: f64, rng: R) -> SampleBernoulli<Self, R>
+    where
+        Self: Sized,
+        R: Rng,
+    {
+        assert!((0.0..=1.0).contains(&p), "`p` must be between zero and one");
+        SampleBernoulli {
+            stream: self,
+            p,
+            rng,
+        }
+    }
+
+    /// Lets items through no more often than once per `period`, as measured by `timer`.
+    ///
+    /// While it has to wait, at most one item is taken from the stream and held back, so that its
+    /// end is seen right away. Waiting returns [`Poll::Pending`] and has `timer` wake the task once
+    /// the next item may pass.
+    fn throttle<T>(self, period: Duration, timer: T) -> Throttle<Self, T>
+    where
+        Self: Sized,
+        T: Timer,
+    {
+        self.rate_limit(1, period, timer)
+    }
+
+    /// Lets bursts of up to `capacity` items through, taking a token from a bucket for every item.
+    /// The bucket starts out full and gains a token every `refill`, as measured by `timer`.
+    ///
+    /// Like [`throttle()`][`StreamExt::throttle()`], at most one item is taken from the stream
+    /// while the bucket is empty, so its end is not held back until a token is available.
+    fn rate_limit<T>(self, capacity: u32, refill: Duration, timer: T) -> RateLimit<Self, T>
+    where
+        Self: Sized,
+        T: Timer,
+    {
+        assert!(capacity > 0, "`capacity` must be greater than zero");
+        assert!(!refill.is_zero(), "`refill` must be greater than zero");
+        RateLimit {
+            stream: self,
+            timer,
+            capacity,
+            refill,
+            tokens: capacity,
+            refilled_at: Duration::ZERO,
+            next: None,
+        }
+    }
+
+    /// Alternates strictly between the items of `self` and `other`, starting with `self`.
+    ///
+    /// A side returning [`Step::NotYet`] keeps its turn. Once a side is done, the rest of the other
+    /// side follows.
+    fn interleave<U>(self, other: U) -> Interleave<Self, U>
+    where
+        Self: Sized,
+        U: Stream<Item = Self::Item> + Sized,
+    {
+        Interleave {
+            first: self.fuse(),
+            second: other.fuse(),
+            second_turn: false,
+        }
+    }
+
+    /// Like [`interleave()`][`StreamExt::interleave()`], but done as soon as the side whose turn it
+    /// is is done.
+    fn interleave_shortest<U>(self, other: U) -> InterleaveShortest<Self, U>
+    where
+        Self: Sized,
+        U: Stream<Item = Self::Item> + Sized,
+    {
+        InterleaveShortest {
+            first: self.fuse(),
+            second: other.fuse(),
+            second_turn: false,
+            done: false,
+        }
+    }
+
+    /// Merges `self` and `other`, both sorted by `cmp`, into one sorted stream.
+    ///
+    /// Both heads are needed to pick the next item, so a side returning [`Step::NotYet`] holds
+    /// back the merge. Equal items from `self` come first.
+    fn merge_sorted_by<U, F>(self, other: U, cmp: F) -> MergeSortedBy<Self, U, F>
+    where
+        Self: Sized,
+        U: Stream<Item = Self::Item> + Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        MergeSortedBy {
+            first: self.fuse(),
+            second: other.fuse(),
+            first_head: None,
+            second_head: None,
+            cmp,
+        }
+    }
+
+    /// Reverses the direction of a [`DoubleEndedStream`].
+    fn rev(self) -> Rev<Self>
+    where
+        Self: DoubleEndedStream + Sized,
+    {
+        Rev { stream: self }
+    }
+
     fn cloned<'a, T>(self) -> Cloned<Self>
     where
         Self: Stream<Item = &'a T> + Sized,
@@ -222,6 +694,14 @@ pub trait StreamExt: Stream {
         Cloned { stream: self }
     }
 
+    fn copied<'a, T>(self) -> Copied<Self>
+    where
+        Self: Stream<Item = &'a T> + Sized,
+        T: Copy + 'a,
+    {
+        Copied { stream: self }
+    }
+
     fn collect<C>(self) -> Collect<Self, C>
     where
         Self: Sized,
@@ -276,6 +756,382 @@ pub trait StreamExt: Stream {
             acc: Some(init),
         }
     }
+
+    /// Folds the stream starting from its back.
+    fn rfold<T, F>(self, init: T, f: F) -> Fold<Rev<Self>, F, T>
+    where
+        Self: DoubleEndedStream + Sized,
+        F: FnMut(T, Self::Item) -> T,
+    {
+        self.rev().fold(init, f)
+    }
+
+    /// Pushes every item into `aggregator`, resolving to it once the stream is done.
+    ///
+    /// ```
+    /// use fusion_core::{consumer::stats::MinMax, prelude::*};
+    ///
+    /// let bounds = spin_on::spin_on((1..=5).into_fusion().aggregate(MinMax::new()).yield_by(32));
+    /// assert_eq!(bounds.into_inner(), Some((1, 5)));
+    /// ```
+    fn aggregate<A>(self, aggregator: A) -> Aggregate<Self, A>
+    where
+        Self: Sized,
+        A: Aggregator<Self::Item>,
+    {
+        Aggregate {
+            stream: self,
+            aggregator: Some(aggregator),
+        }
+    }
+
+    /// Computes the count, mean and variance of the items.
+    ///
+    /// ```
+    /// use fusion_core::prelude::*;
+    ///
+    /// let moments = spin_on::spin_on((1..=5).into_fusion().moments().yield_by(32));
+    /// assert_eq!(moments.mean(), Some(3.0));
+    /// assert_eq!(moments.variance(), Some(2.0));
+    /// ```
+    fn moments(self) -> Aggregate<Self, Moments>
+    where
+        Self: Sized,
+        Self::Item: AsF64,
+    {
+        self.aggregate(Moments::new())
+    }
+
+    /// Finds the smallest and largest items, see [`MinMax`].
+    fn min_max(self) -> Aggregate<Self, MinMax<Self::Item>>
+    where
+        Self: Sized,
+        Self::Item: PartialOrd + Clone,
+    {
+        self.aggregate(MinMax::new())
+    }
+
+    /// Sketches the distribution of the items to answer quantile queries with a rank error of at
+    /// most `epsilon` times the number of items, see [`Quantiles`].
+    #[cfg(feature = "alloc")]
+    fn quantiles(self, epsilon: f64) -> Aggregate<Self, Quantiles<Self::Item>>
+    where
+        Self: Sized,
+        Self::Item: PartialOrd,
+    {
+        self.aggregate(Quantiles::new(epsilon))
+    }
+
+    /// Counts the items falling into each of `buckets` equally wide buckets spanning `range`.
+    #[cfg(feature = "alloc")]
+    fn histogram(self, range: core::ops::Range<f64>, buckets: usize) -> Aggregate<Self, Histogram>
+    where
+        Self: Sized,
+        Self::Item: AsF64,
+    {
+        self.aggregate(Histogram::new(range, buckets))
+    }
+
+    /// Estimates the number of distinct items with a [`HyperLogLog`] sketch of `2^precision`
+    /// registers.
+    ///
+    /// ```
+    /// use fusion_core::prelude::*;
+    ///
+    /// let sketch = (0..10_000)
+    ///     .map(|i| i % 1000)
+    ///     .into_fusion()
+    ///     .approx_count_distinct(12);
+    /// let distinct = spin_on::spin_on(sketch.yield_by(256)).count();
+    /// assert!(distinct.abs_diff(1000) < 50);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn approx_count_distinct(self, precision: u8) -> Aggregate<Self, HyperLogLog>
+    where
+        Self: Sized,
+        Self::Item: core::hash::Hash,
+    {
+        self.aggregate(HyperLogLog::new(precision))
+    }
+
+    /// Finds the most frequent items, counting at most `k` of them at a time, see [`TopK`].
+    #[cfg(feature = "alloc")]
+    fn top_k_frequent(self, k: usize) -> Aggregate<Self, TopK<Self::Item>>
+    where
+        Self: Sized,
+        Self::Item: Eq + core::hash::Hash + Clone,
+    {
+        self.aggregate(TopK::new(k))
+    }
+
+    /// Picks `k` items uniformly at random, or all of them if there are fewer, see [`Reservoir`].
+    ///
+    /// ```
+    /// use fusion_core::{prelude::*, rng::SplitMix64};
+    ///
+    /// let sample = (0..1000)
+    ///     .into_fusion()
+    ///     .sample_reservoir(10, SplitMix64::new(7));
+    /// let sample = spin_on::spin_on(sample.yield_by(64));
+    /// assert_eq!(sample.seen(), 1000);
+    /// assert_eq!(sample.samples().len(), 10);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn sample_reservoir<R>(self, k: usize, rng: R) -> Aggregate<Self, Reservoir<Self::Item, R>>
+    where
+        Self: Sized,
+        R: Rng,
+    {
+        self.aggregate(Reservoir::new(k, rng))
+    }
+
+    /// Pushes every item into `aggregator` and yields what `f` reads from it afterwards.
+    ///
+    /// ```
+    /// use fusion_core::{consumer::stats::Moments, prelude::*};
+    ///
+    /// let means = (1..=4)
+    ///     .into_fusion()
+    ///     .running(Moments::new(), |m| m.mean().unwrap());
+    /// let means: Vec<f64> = spin_on::spin_on(means.collect().yield_by(32));
+    /// assert_eq!(means, [1.0, 1.5, 2.0, 2.5]);
+    /// ```
+    fn running<A, F, T>(self, aggregator: A, f: F) -> Running<Self, A, F>
+    where
+        Self: Sized,
+        A: Aggregator<Self::Item>,
+        F: FnMut(&A) -> T,
+    {
+        Running {
+            stream: self,
+            aggregator,
+            f,
+        }
+    }
+
+    fn find<P>(self, predicate: P) -> Find<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        Find {
+            stream: self,
+            predicate,
+        }
+    }
+
+    /// Searches the stream for an item satisfying `predicate`, starting from its back.
+    fn rfind<P>(self, predicate: P) -> Find<Rev<Self>, P>
+    where
+        Self: DoubleEndedStream + Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        self.rev().find(predicate)
+    }
+
+    /// Resolves to the last item of the stream, draining it.
+    ///
+    /// Use [`last_back()`][`StreamExt::last_back()`] to avoid draining a [`DoubleEndedStream`].
+    fn last(self) -> Last<Self>
+    where
+        Self: Sized,
+    {
+        Last {
+            stream: self,
+            last: None,
+        }
+    }
+
+    /// Resolves to the last item of the stream like [`last()`][`StreamExt::last()`], but polls it
+    /// from the back instead of draining it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fusion_core::prelude::*;
+    ///
+    /// # spin_on::spin_on(async {
+    /// let last = (0..10)
+    ///     .into_fusion()
+    ///     .filter(|i| i % 4 == 0)
+    ///     .last_back()
+    ///     .yield_by(32)
+    ///     .await;
+    ///
+    /// assert_eq!(last, Some(8));
+    /// # });
+    /// ```
+    fn last_back(self) -> LastBack<Self>
+    where
+        Self: DoubleEndedStream + Sized,
+    {
+        LastBack { stream: self }
+    }
+
+    /// Folds the stream like [`fold()`][`StreamExt::fold()`], but lets `f` stop early.
+    ///
+    /// Resolves to [`ControlFlow::Break`] with the value `f` broke with, or to
+    /// [`ControlFlow::Continue`] with the final accumulator once the stream is done.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::ops::ControlFlow;
+    ///
+    /// use fusion_core::prelude::*;
+    ///
+    /// # spin_on::spin_on(async {
+    /// let first_large = (1..)
+    ///     .into_fusion()
+    ///     .fold_while(0, |acc, item| {
+    ///         let acc = acc + item;
+    ///         if acc > 10 {
+    ///             ControlFlow::Break(item)
+    ///         } else {
+    ///             ControlFlow::Continue(acc)
+    ///         }
+    ///     })
+    ///     .yield_by(32)
+    ///     .await;
+    ///
+    /// assert_eq!(first_large, ControlFlow::Break(5));
+    /// # });
+    /// ```
+    fn fold_while<T, B, F>(self, init: T, f: F) -> FoldWhile<Self, F, T>
+    where
+        Self: Sized,
+        F: FnMut(T, Self::Item) -> ControlFlow<B, T>,
+    {
+        FoldWhile {
+            stream: self,
+            f,
+            acc: Some(init),
+        }
+    }
+
+    /// Consumes a stream of `Result`s, collecting every `Ok` item into `A` and every `Err` into
+    /// `B` instead of stopping at the first error like
+    /// [`try_collect()`][`StreamExt::try_collect()`] does.
+    fn partition_result<T, E, A, B>(self) -> PartitionResult<Self, A, B>
+    where
+        Self: Stream<Item = Result<T, E>> + Sized,
+        A: Default + Extend<T>,
+        B: Default + Extend<E>,
+    {
+        #[allow(unused_mut)]
+        let mut collection: A = Default::default();
+        #[cfg(feature = "nightly")]
+        collection.extend_reserve(self.size_hint().0);
+        PartitionResult {
+            stream: self,
+            res: Some((collection, Default::default())),
+        }
+    }
+
+    /// Collects the `Ok` items of the stream into `C` and all of its errors into a [`Vec`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fusion_core::prelude::*;
+    ///
+    /// # spin_on::spin_on(async {
+    /// let (ok, err): (Vec<_>, _) = vec![Ok(1), Err("a"), Ok(3), Err("b")]
+    ///     .into_iter()
+    ///     .into_fusion()
+    ///     .collect_results()
+    ///     .yield_by(32)
+    ///     .await;
+    ///
+    /// assert_eq!(ok, [1, 3]);
+    /// assert_eq!(err, ["a", "b"]);
+    /// # });
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn collect_results<T, E, C>(self) -> PartitionResult<Self, C, alloc::vec::Vec<E>>
+    where
+        Self: Stream<Item = Result<T, E>> + Sized,
+        C: Default + Extend<T>,
+    {
+        self.partition_result()
+    }
+
+    /// Partitions the `Ok` items of the stream like [`partition()`][`StreamExt::partition()`],
+    /// stopping at the first `Err` and returning it together with both partial collections.
+    fn try_partition<T, E, B, P>(self, predicate: P) -> TryPartition<Self, P, B>
+    where
+        Self: Stream<Item = Result<T, E>> + Sized,
+        B: Default + Extend<T>,
+        P: FnMut(&T) -> bool,
+    {
+        TryPartition {
+            stream: self,
+            predicate,
+            res: Some(Default::default()),
+        }
+    }
+
+    /// Folds the `Ok` items of the stream into an accumulator with a closure returning a
+    /// [`Result`] or a [`ControlFlow`], like [`Iterator::try_fold()`].
+    ///
+    /// Resolves to `Ok` with whatever `f` stopped with, or with the final accumulator once the
+    /// stream is done. The first `Err` of the stream stops the fold and is returned together with
+    /// the accumulator as it was at that point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fusion_core::prelude::*;
+    ///
+    /// # spin_on::spin_on(async {
+    /// let sum = vec![Ok(1), Ok(2), Err("error"), Ok(4)]
+    ///     .into_iter()
+    ///     .into_fusion()
+    ///     .try_fold(0, |acc, item| Ok::<_, ()>(acc + item))
+    ///     .yield_by(32)
+    ///     .await;
+    /// assert_eq!(sum, Err(("error", 3)));
+    ///
+    /// let sum = vec![Ok::<_, ()>(200u8), Ok(100)]
+    ///     .into_iter()
+    ///     .into_fusion()
+    ///     .try_fold(0u8, |acc, item| acc.checked_add(item).ok_or("overflow"))
+    ///     .yield_by(32)
+    ///     .await;
+    /// assert_eq!(sum, Ok(Err("overflow")));
+    /// # });
+    /// ```
+    fn try_fold<T, E, B, F, R>(self, init: B, f: F) -> TryFold<Self, F, B>
+    where
+        Self: Stream<Item = Result<T, E>> + Sized,
+        F: FnMut(B, T) -> R,
+        R: Try<Output = B>,
+    {
+        TryFold {
+            stream: self,
+            f,
+            acc: Some(init),
+        }
+    }
+
+    /// Reduces the `Ok` items of the stream into the first one with a closure returning a
+    /// [`Result`] or a [`ControlFlow`].
+    ///
+    /// Resolves to `Ok(None)` for an empty stream, and otherwise to `Ok` with whatever `f` stopped
+    /// with or the reduced value. The first `Err` of the stream stops the reduction and is
+    /// returned together with the partially reduced value.
+    fn try_reduce<T, E, F, R>(self, f: F) -> TryReduce<Self, F, T>
+    where
+        Self: Stream<Item = Result<T, E>> + Sized,
+        F: FnMut(T, T) -> R,
+        R: Try<Output = T>,
+    {
+        TryReduce {
+            stream: self,
+            f,
+            acc: None,
+        }
+    }
 }
 
 impl<S: Stream + ?Sized> StreamExt for S {}
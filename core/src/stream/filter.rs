@@ -1,4 +1,5 @@
 use core::{
+    ops::ControlFlow,
     pin::Pin,
     task::{Context, Poll},
 };
@@ -6,7 +7,7 @@ use core::{
 use futures_core::ready;
 use pin_project_lite::pin_project;
 
-use super::Stream;
+use super::{coalesce, DoubleEndedStream, Stream};
 use crate::step::Step;
 
 pin_project! {
@@ -15,6 +16,21 @@ pin_project! {
         #[pin]
         pub(crate) stream: S,
         pub(crate) predicate: P,
+        pub(crate) coalesce: usize,
+    }
+}
+
+impl<S, P> Filter<S, P> {
+    /// Steps over up to `n` rejected items in a single poll before surfacing [`Step::NotYet`],
+    /// instead of one per rejected item.
+    ///
+    /// When driven through [`poll_fold_n()`][`Stream::poll_fold_n()`], a run of up to `n` rejected
+    /// items takes a single step of the execution budget, the run being capped by the budget
+    /// itself. [`Step::NotYet`] from the stream is still surfaced right away. Defaults to `1`.
+    pub fn coalesce(mut self, n: usize) -> Self {
+        assert!(n > 0, "`n` must be greater than zero");
+        self.coalesce = n;
+        self
     }
 }
 
@@ -25,22 +41,55 @@ where
 {
     type Item = S::Item;
 
+    const SYNC: bool = S::SYNC;
+
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
         let mut this = self.project();
-        return Poll::Ready(match ready!(this.stream.as_mut().poll_next(cx)) {
-            Step::NotYet => Step::NotYet,
-            Step::Ready(item) => {
-                if (this.predicate)(&item) {
-                    Step::Ready(item)
-                } else {
-                    Step::NotYet
-                }
+        for _ in 0..*this.coalesce {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Step::Ready(item) if !(this.predicate)(&item) => {}
+                next => return Poll::Ready(next),
             }
-            Step::Done => Step::Done,
-        });
+        }
+        Poll::Ready(Step::NotYet)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
         (0, self.stream.size_hint().1)
     }
+
+    #[inline]
+    fn poll_fold_n<B, F>(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        budget: &mut usize,
+        acc: &mut B,
+        mut f: F,
+    ) -> Poll<Step<()>>
+    where
+        F: FnMut(&mut B, Self::Item) -> ControlFlow<()>,
+    {
+        let this = self.project();
+        let predicate = this.predicate;
+        coalesce::poll_fold_n(this.stream, cx, budget, *this.coalesce, acc, |acc, item| {
+            predicate(&item).then(|| f(acc, item))
+        })
+    }
+}
+
+impl<S, P> DoubleEndedStream for Filter<S, P>
+where
+    S: DoubleEndedStream,
+    P: FnMut(&S::Item) -> bool,
+{
+    fn poll_next_back(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let mut this = self.project();
+        for _ in 0..*this.coalesce {
+            match ready!(this.stream.as_mut().poll_next_back(cx)) {
+                Step::Ready(item) if !(this.predicate)(&item) => {}
+                next => return Poll::Ready(next),
+            }
+        }
+        Poll::Ready(Step::NotYet)
+    }
 }
@@ -25,6 +25,8 @@ where
 {
     type Item = U::Item;
 
+    const SYNC: bool = S::SYNC && U::SYNC;
+
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
         let mut this = self.project();
 
@@ -46,4 +48,15 @@ where
             }
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self
+            .inner_stream
+            .as_ref()
+            .map_or((0, Some(0)), |inner| inner.size_hint());
+        match self.stream.size_hint() {
+            (0, Some(0)) => (lower, upper),
+            _ => (lower, None),
+        }
+    }
 }
@@ -6,7 +6,7 @@ use core::{
 use futures_core::ready;
 use pin_project_lite::pin_project;
 
-use super::Stream;
+use super::{DoubleEndedStream, ExactSizeStream, Stream};
 use crate::step::Step;
 
 pin_project! {
@@ -18,9 +18,33 @@ pin_project! {
     }
 }
 
+impl<S: Stream> Fuse<S> {
+    /// Polls the next item into an empty `head`. Resolves to `Step::Ready(())` once `head` holds
+    /// an item or the stream is done.
+    pub(super) fn poll_head(
+        self: Pin<&mut Self>,
+        head: &mut Option<S::Item>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Step<()>> {
+        if head.is_some() || self.done {
+            return Poll::Ready(Step::Ready(()));
+        }
+        self.poll_next(cx).map(|next| match next {
+            Step::Ready(item) => {
+                *head = Some(item);
+                Step::Ready(())
+            }
+            Step::NotYet => Step::NotYet,
+            Step::Done => Step::Ready(()),
+        })
+    }
+}
+
 impl<S: Stream> Stream for Fuse<S> {
     type Item = S::Item;
 
+    const SYNC: bool = S::SYNC;
+
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<S::Item>> {
         let this = self.project();
 
@@ -34,4 +58,30 @@ impl<S: Stream> Stream for Fuse<S> {
             Poll::Ready(next)
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            self.stream.size_hint()
+        }
+    }
+}
+
+impl<S: ExactSizeStream> ExactSizeStream for Fuse<S> {}
+
+impl<S: DoubleEndedStream> DoubleEndedStream for Fuse<S> {
+    fn poll_next_back(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<S::Item>> {
+        let this = self.project();
+
+        if *this.done {
+            Poll::Ready(Step::Done)
+        } else {
+            let next = ready!(this.stream.poll_next_back(cx));
+            if let Step::Done = next {
+                *this.done = true;
+            }
+            Poll::Ready(next)
+        }
+    }
 }
@@ -0,0 +1,321 @@
+use alloc::rc::Rc;
+use core::{
+    cell::Cell,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use pin_project_lite::pin_project;
+
+use super::Stream;
+use crate::step::Step;
+
+/// Builds a stream from an async block that yields through the given [`Co`].
+///
+/// ```
+/// use fusion_core::{prelude::*, stream::generator::from_generator};
+///
+/// let stream = from_generator(|co| async move {
+///     for i in 0..10 {
+///         if i % 2 == 0 {
+///             co.yield_(i).await;
+///         } else {
+///             co.yield_not_yet().await;
+///         }
+///     }
+/// });
+/// let evens: Vec<i32> = spin_on::spin_on(stream.collect().yield_by(32));
+/// assert_eq!(evens, [0, 2, 4, 6, 8]);
+/// ```
+pub fn from_generator<T, F, Fut>(f: F) -> Generator<T, Fut>
+where
+    F: FnOnce(Co<T>) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    let slot = Rc::new(Slot::new());
+    Generator {
+        future: Some(f(Co { slot: slot.clone() })),
+        slot,
+    }
+}
+
+/// Like [`from_generator()`], but the body may fail. The error is yielded as the last item.
+pub fn try_from_generator<T, E, F, Fut>(f: F) -> TryGenerator<T, Fut>
+where
+    F: FnOnce(Co<T>) -> Fut,
+    Fut: Future<Output = Result<(), E>>,
+{
+    let slot = Rc::new(Slot::new());
+    TryGenerator {
+        future: Some(f(Co { slot: slot.clone() })),
+        slot,
+    }
+}
+
+/// Handle through which a generator body hands steps to its stream.
+pub struct Co<T> {
+    slot: Rc<Slot<T>>,
+}
+
+impl<T> Co<T> {
+    /// Yields `item` as a [`Step::Ready`].
+    pub fn yield_(&self, item: T) -> Yield<'_, T> {
+        Yield {
+            co: self,
+            step: Some(Step::Ready(item)),
+        }
+    }
+
+    /// Yields a [`Step::NotYet`], handing control back to the consumer without an item.
+    pub fn yield_not_yet(&self) -> Yield<'_, T> {
+        Yield {
+            co: self,
+            step: Some(Step::NotYet),
+        }
+    }
+}
+
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Yield<'a, T> {
+    co: &'a Co<T>,
+    step: Option<Step<T>>,
+}
+
+impl<T> Unpin for Yield<'_, T> {}
+
+impl<T> Future for Yield<'_, T> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match self.step.take() {
+            // The generator stream picks the step up as soon as the body returns `Pending`, and
+            // polls it again on the next `poll_next`, so there is no need to wake anything.
+            Some(step) => {
+                self.co.slot.put(step);
+                Poll::Pending
+            }
+            None => Poll::Ready(()),
+        }
+    }
+}
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Generator<T, Fut> {
+        slot: Rc<Slot<T>>,
+        #[pin]
+        future: Option<Fut>,
+    }
+}
+
+impl<T, Fut> Stream for Generator<T, Fut>
+where
+    Fut: Future<Output = ()>,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let mut this = self.project();
+        let Some(future) = this.future.as_mut().as_pin_mut() else {
+            return Poll::Ready(Step::Done);
+        };
+        match this.slot.poll(future, cx) {
+            Poll::Ready(()) => {
+                this.future.set(None);
+                Poll::Ready(Step::Done)
+            }
+            Poll::Pending => this.slot.take().map_or(Poll::Pending, Poll::Ready),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.future {
+            Some(_) => (0, None),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct TryGenerator<T, Fut> {
+        slot: Rc<Slot<T>>,
+        #[pin]
+        future: Option<Fut>,
+    }
+}
+
+impl<T, E, Fut> Stream for TryGenerator<T, Fut>
+where
+    Fut: Future<Output = Result<(), E>>,
+{
+    type Item = Result<T, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let mut this = self.project();
+        let Some(future) = this.future.as_mut().as_pin_mut() else {
+            return Poll::Ready(Step::Done);
+        };
+        match this.slot.poll(future, cx) {
+            Poll::Ready(result) => {
+                this.future.set(None);
+                Poll::Ready(match result {
+                    Ok(()) => Step::Done,
+                    Err(e) => Step::Ready(Err(e)),
+                })
+            }
+            Poll::Pending => match this.slot.take() {
+                Some(step) => Poll::Ready(step.map(Ok)),
+                None => Poll::Pending,
+            },
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.future {
+            Some(_) => (0, None),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+/// Single step hand-off between a [`Co`] and its generator stream.
+struct Slot<T> {
+    /// Whether the stream is polling its body, the only time a [`Co`] may yield.
+    polling: Cell<bool>,
+    step: Cell<Option<Step<T>>>,
+}
+
+impl<T> Slot<T> {
+    fn new() -> Self {
+        Slot {
+            polling: Cell::new(false),
+            step: Cell::new(None),
+        }
+    }
+
+    fn poll<F: Future>(&self, body: Pin<&mut F>, cx: &mut Context<'_>) -> Poll<F::Output> {
+        self.polling.set(true);
+        let poll = body.poll(cx);
+        self.polling.set(false);
+        poll
+    }
+
+    fn put(&self, step: Step<T>) {
+        assert!(
+            self.polling.get(),
+            "generator yielded from outside of its stream"
+        );
+        let stale = self.step.replace(Some(step));
+        assert!(
+            stale.is_none(),
+            "generator yielded twice without being polled"
+        );
+    }
+
+    fn take(&self) -> Option<Step<T>> {
+        self.step.take()
+    }
+}
+
+/// Builds a fused stream out of a block that yields through `$co`.
+///
+/// ```
+/// use fusion_core::{prelude::*, stream};
+///
+/// let squares = stream! { co =>
+///     for i in 1..=4 {
+///         co.yield_(i * i).await;
+///     }
+/// };
+/// let squares: Vec<i32> = spin_on::spin_on(squares.collect().yield_by(32));
+/// assert_eq!(squares, [1, 4, 9, 16]);
+/// ```
+#[macro_export]
+macro_rules! stream {
+    ($co:ident => $($body:tt)*) => {
+        $crate::stream::generator::from_generator(move |$co| async move { $($body)* })
+    };
+}
+
+/// Like [`stream!`], but the block may use `?`. The first error is yielded as the last item.
+///
+/// ```
+/// use core::num::ParseIntError;
+///
+/// use fusion_core::{prelude::*, try_stream};
+///
+/// let parsed = try_stream! { co =>
+///     for s in ["1", "2", "x", "4"] {
+///         co.yield_(s.parse::<i32>()?).await;
+///     }
+/// };
+/// let parsed: Vec<Result<i32, ParseIntError>> = spin_on::spin_on(parsed.collect().yield_by(32));
+/// assert_eq!(parsed.len(), 3);
+/// assert!(parsed[2].is_err());
+/// ```
+#[macro_export]
+macro_rules! try_stream {
+    ($co:ident => $($body:tt)*) => {
+        $crate::stream::generator::try_from_generator(move |$co| async move {
+            { $($body)* }
+            ::core::result::Result::Ok(())
+        })
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use core::{
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+    use std::vec::Vec;
+
+    use crate::{
+        consumer::ConsumerExt,
+        step::Step,
+        stream::{ext::StreamExt, Stream},
+    };
+
+    #[test]
+    fn generator() {
+        use core::num::ParseIntError;
+
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut stream = pin!(crate::stream! { co =>
+            co.yield_(1).await;
+            co.yield_not_yet().await;
+            co.yield_(2).await;
+        });
+        for expect in [
+            Step::Ready(1),
+            Step::NotYet,
+            Step::Ready(2),
+            Step::Done,
+            Step::Done,
+        ] {
+            assert_eq!(stream.as_mut().poll_next(&mut cx), Poll::Ready(expect));
+        }
+        assert_eq!(stream.size_hint(), (0, Some(0)));
+
+        let parse = |input: &'static [&'static str]| {
+            crate::try_stream! { co =>
+                for s in input {
+                    co.yield_(s.parse::<i32>()?).await;
+                }
+            }
+        };
+        let parsed: Vec<Result<i32, ParseIntError>> =
+            spin_on::spin_on(parse(&["1", "x", "3"]).collect().yield_by(32));
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[0], Ok(1));
+        assert!(parsed[1].is_err());
+        let parsed: Vec<Result<i32, ParseIntError>> =
+            spin_on::spin_on(parse(&["1", "2"]).collect().yield_by(1));
+        assert_eq!(parsed, [Ok(1), Ok(2)]);
+    }
+}
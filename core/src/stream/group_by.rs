@@ -0,0 +1,147 @@
+use alloc::{boxed::Box, rc::Rc};
+use core::{
+    cell::RefCell,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use super::Stream;
+use crate::{ready_step, step::Step};
+
+struct Shared<S: Stream, F, K> {
+    stream: Pin<Box<S>>,
+    key_fn: F,
+    /// The item read past the end of the current group, or the first item of a group that has
+    /// not been handed out yet.
+    head: Option<(K, S::Item)>,
+    key: Option<K>,
+    group: usize,
+    done: bool,
+}
+
+impl<S, F, K> Shared<S, F, K>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> K,
+{
+    /// Reads the next item into `head`.
+    fn poll_head(&mut self, cx: &mut Context<'_>) -> Poll<Step<()>> {
+        if self.done {
+            return Poll::Ready(Step::Done);
+        }
+        self.stream.as_mut().poll_next(cx).map(|next| match next {
+            Step::Ready(item) => {
+                self.head = Some(((self.key_fn)(&item), item));
+                Step::Ready(())
+            }
+            Step::NotYet => Step::NotYet,
+            Step::Done => {
+                self.done = true;
+                Step::Done
+            }
+        })
+    }
+}
+
+#[must_use = "streams do nothing unless polled"]
+pub struct GroupBy<S: Stream, F, K> {
+    shared: Rc<RefCell<Shared<S, F, K>>>,
+}
+
+impl<S: Stream, F, K> GroupBy<S, F, K> {
+    pub(super) fn new(stream: S, key_fn: F) -> Self {
+        GroupBy {
+            shared: Rc::new(RefCell::new(Shared {
+                stream: Box::pin(stream),
+                key_fn,
+                head: None,
+                key: None,
+                group: 0,
+                done: false,
+            })),
+        }
+    }
+}
+
+impl<S: Stream, F, K> Unpin for GroupBy<S, F, K> {}
+
+impl<S, F, K> Stream for GroupBy<S, F, K>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> K,
+    K: PartialEq + Clone,
+{
+    type Item = (K, Group<S, F, K>);
+
+    const SYNC: bool = S::SYNC;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let mut shared = self.shared.borrow_mut();
+
+        loop {
+            match &shared.head {
+                Some((key, _)) if shared.key.as_ref() != Some(key) => {
+                    let key = key.clone();
+                    shared.key = Some(key.clone());
+                    shared.group += 1;
+                    let group = Group {
+                        shared: self.shared.clone(),
+                        index: shared.group,
+                    };
+                    return Poll::Ready(Step::Ready((key, group)));
+                }
+                // Skips what is left of the current group.
+                Some(_) => {
+                    shared.head = None;
+                    return Poll::Ready(Step::NotYet);
+                }
+                None => ready_step!(shared.poll_head(cx)),
+            }
+        }
+    }
+}
+
+/// The items of a [`GroupBy`] sharing one key.
+///
+/// A group reads from the stream of its [`GroupBy`], so it yields nothing once the [`GroupBy`]
+/// has moved on to the next group.
+#[must_use = "streams do nothing unless polled"]
+pub struct Group<S: Stream, F, K> {
+    shared: Rc<RefCell<Shared<S, F, K>>>,
+    index: usize,
+}
+
+impl<S: Stream, F, K> Unpin for Group<S, F, K> {}
+
+impl<S, F, K> Stream for Group<S, F, K>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> K,
+    K: PartialEq,
+{
+    type Item = S::Item;
+
+    const SYNC: bool = S::SYNC;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let mut shared = self.shared.borrow_mut();
+        if shared.group != self.index {
+            return Poll::Ready(Step::Done);
+        }
+
+        if shared.head.is_none() {
+            ready_step!(shared.poll_head(cx));
+        }
+        let shared = &mut *shared;
+        match shared.head.take() {
+            Some((key, item)) if shared.key.as_ref() == Some(&key) => {
+                Poll::Ready(Step::Ready(item))
+            }
+            head => {
+                // The first item of the next group.
+                shared.head = head;
+                Poll::Ready(Step::Done)
+            }
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::ready;
+use pin_project_lite::pin_project;
+
+use super::{fuse::Fuse, Stream};
+use crate::step::Step;
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Interleave<S, U> {
+        #[pin]
+        pub(super) first: Fuse<S>,
+        #[pin]
+        pub(super) second: Fuse<U>,
+        pub(super) second_turn: bool,
+    }
+}
+
+impl<S: Stream, U: Stream<Item = S::Item>> Stream for Interleave<S, U> {
+    type Item = S::Item;
+
+    const SYNC: bool = S::SYNC && U::SYNC;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let mut this = self.project();
+
+        // Once a side is done, the other one gets every turn.
+        for _ in 0..2 {
+            let next = if *this.second_turn {
+                ready!(this.second.as_mut().poll_next(cx))
+            } else {
+                ready!(this.first.as_mut().poll_next(cx))
+            };
+            match next {
+                Step::Ready(item) => {
+                    *this.second_turn = !*this.second_turn;
+                    return Poll::Ready(Step::Ready(item));
+                }
+                Step::NotYet => return Poll::Ready(Step::NotYet),
+                Step::Done => *this.second_turn = !*this.second_turn,
+            }
+        }
+        Poll::Ready(Step::Done)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (first_lower, first_upper) = self.first.size_hint();
+        let (second_lower, second_upper) = self.second.size_hint();
+        let lower = first_lower.saturating_add(second_lower);
+        let upper = match (first_upper, second_upper) {
+            (Some(first), Some(second)) => first.checked_add(second),
+            _ => None,
+        };
+        (lower, upper)
+    }
+}
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct InterleaveShortest<S, U> {
+        #[pin]
+        pub(super) first: Fuse<S>,
+        #[pin]
+        pub(super) second: Fuse<U>,
+        pub(super) second_turn: bool,
+        pub(super) done: bool,
+    }
+}
+
+impl<S: Stream, U: Stream<Item = S::Item>> Stream for InterleaveShortest<S, U> {
+    type Item = S::Item;
+
+    const SYNC: bool = S::SYNC && U::SYNC;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let mut this = self.project();
+
+        if *this.done {
+            return Poll::Ready(Step::Done);
+        }
+        let next = if *this.second_turn {
+            ready!(this.second.as_mut().poll_next(cx))
+        } else {
+            ready!(this.first.as_mut().poll_next(cx))
+        };
+        match next {
+            Step::Ready(_) => *this.second_turn = !*this.second_turn,
+            Step::NotYet => {}
+            Step::Done => *this.done = true,
+        }
+        Poll::Ready(next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let (mut current, mut other) = (self.first.size_hint(), self.second.size_hint());
+        if self.second_turn {
+            (current, other) = (other, current);
+        }
+        // The side whose turn it is yields first, so it is out of items once it has yielded as
+        // many as the other side, or one more if the other side runs out first.
+        let len = |current: usize, other: usize| {
+            current
+                .saturating_mul(2)
+                .min(other.saturating_mul(2).saturating_add(1))
+        };
+        let lower = len(current.0, other.0);
+        let upper = match (
+            current.1.and_then(|current| current.checked_mul(2)),
+            other
+                .1
+                .and_then(|other| other.checked_mul(2)?.checked_add(1)),
+        ) {
+            (Some(current), Some(other)) => Some(current.min(other)),
+            (Some(upper), None) | (None, Some(upper)) => Some(upper),
+            (None, None) => None,
+        };
+        (lower, upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::stream::{
+        ext::StreamExt,
+        tests::{assert_size_hints, drain},
+        IntoFusion, IteratorStream,
+    };
+
+    #[test]
+    fn interleave() {
+        use crate::stream::round_robin;
+
+        assert_size_hints(
+            (0..3).into_fusion().interleave((10..15).into_fusion()),
+            [0, 10, 1, 11, 2, 12, 13, 14].into_iter(),
+        );
+        assert_size_hints(
+            (0..3)
+                .into_fusion()
+                .interleave_shortest((10..15).into_fusion()),
+            [0, 10, 1, 11, 2, 12].into_iter(),
+        );
+        assert_size_hints(
+            (0..5)
+                .into_fusion()
+                .interleave_shortest((10..12).into_fusion()),
+            [0, 10, 1, 11, 2].into_iter(),
+        );
+        // Rejections keep the turn on the rejecting side instead of passing it on.
+        assert_eq!(
+            drain(
+                (0..10)
+                    .into_fusion()
+                    .filter(|i| i % 3 == 0)
+                    .interleave((10..13).into_fusion())
+            ),
+            [0, 10, 3, 11, 6, 12, 9]
+        );
+
+        assert_size_hints(
+            round_robin([
+                (0..2).into_fusion(),
+                (10..14).into_fusion(),
+                (20..23).into_fusion(),
+            ]),
+            [0, 10, 20, 1, 11, 21, 12, 22, 13].into_iter(),
+        );
+        assert_eq!(
+            drain(round_robin(std::vec![
+                (0..6).into_fusion().step_by(2),
+                (10..11).into_fusion().step_by(1),
+            ])),
+            [0, 10, 2, 4]
+        );
+        assert_eq!(
+            drain(round_robin::<[IteratorStream<core::ops::Range<i32>>; 0], _>([])),
+            []
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use alloc::vec::{self, Vec};
+use core::{
+    hash::Hash,
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::ready;
+use hashbrown::HashMap;
+use pin_project_lite::pin_project;
+
+use super::{JoinKind, Joined};
+use crate::{
+    step::Step,
+    stream::{ext::StreamExt, fuse::Fuse, Stream},
+};
+
+/// Joins `build` and `probe` on `key_a(a) == key_b(b)`.
+///
+/// `build` is read into a hash table before the first item of `probe` is polled, and stays the
+/// left side of the join. Every item of `probe` is then matched against the table, yielding one
+/// [`Joined::Both`] per matching item of `build`. Inner by default, see [`HashJoin::kind()`].
+///
+/// Unmatched items of `build` come last, in the order `build` yielded them.
+///
+/// ```
+/// use fusion_core::{
+///     prelude::*,
+///     stream::join::{hash_join, JoinKind, Joined},
+/// };
+///
+/// let users = [(1, "ann"), (2, "bob")].into_iter().into_fusion();
+/// let orders = [(1, 'a'), (3, 'b'), (1, 'c')].into_iter().into_fusion();
+/// let joined: Vec<_> = spin_on::spin_on(
+///     hash_join(users, orders, |u| u.0, |o| o.0)
+///         .kind(JoinKind::Outer)
+///         .map(|j| match j {
+///             Joined::Both(u, o) => (Some(u.1), Some(o.1)),
+///             Joined::Left(u) => (Some(u.1), None),
+///             Joined::Right(o) => (None, Some(o.1)),
+///         })
+///         .collect()
+///         .yield_by(32),
+/// );
+/// assert_eq!(
+///     joined,
+///     [
+///         (Some("ann"), Some('a')),
+///         (None, Some('b')),
+///         (Some("ann"), Some('c')),
+///         (Some("bob"), None),
+///     ]
+/// );
+/// ```
+pub fn hash_join<A, B, K, FA, FB>(
+    build: A,
+    probe: B,
+    key_a: FA,
+    key_b: FB,
+) -> HashJoin<A, B, K, FA, FB>
+where
+    A: Stream,
+    B: Stream,
+    K: Hash + Eq,
+    FA: FnMut(&A::Item) -> K,
+    FB: FnMut(&B::Item) -> K,
+{
+    HashJoin {
+        build: build.fuse(),
+        probe: probe.fuse(),
+        key_a,
+        key_b,
+        kind: JoinKind::Inner,
+        rows: Vec::new(),
+        table: HashMap::new(),
+        matching: None,
+        unmatched: None,
+    }
+}
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct HashJoin<A: Stream, B: Stream, K, FA, FB> {
+        #[pin]
+        build: Fuse<A>,
+        #[pin]
+        probe: Fuse<B>,
+        key_a: FA,
+        key_b: FB,
+        kind: JoinKind,
+        // The items of `build`, and whether they were matched.
+        rows: Vec<(A::Item, bool)>,
+        // The indices into `rows` of every key.
+        table: HashMap<K, Vec<usize>>,
+        // The probe item being matched, its key and the next row of the table to match it with.
+        matching: Option<(B::Item, K, usize)>,
+        unmatched: Option<vec::IntoIter<(A::Item, bool)>>,
+    }
+}
+
+impl<A: Stream, B: Stream, K, FA, FB> HashJoin<A, B, K, FA, FB> {
+    pub fn kind(mut self, kind: JoinKind) -> Self {
+        self.kind = kind;
+        self
+    }
+}
+
+impl<A, B, K, FA, FB> Stream for HashJoin<A, B, K, FA, FB>
+where
+    A: Stream,
+    A::Item: Clone,
+    B: Stream,
+    B::Item: Clone,
+    K: Hash + Eq,
+    FA: FnMut(&A::Item) -> K,
+    FB: FnMut(&B::Item) -> K,
+{
+    type Item = Joined<A::Item, B::Item>;
+
+    const SYNC: bool = A::SYNC && B::SYNC;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let mut this = self.project();
+
+        if !this.build.done {
+            match ready!(this.build.as_mut().poll_next(cx)) {
+                Step::Ready(item) => {
+                    let key = (this.key_a)(&item);
+                    this.table.entry(key).or_default().push(this.rows.len());
+                    this.rows.push((item, false));
+                    return Poll::Ready(Step::NotYet);
+                }
+                Step::NotYet => return Poll::Ready(Step::NotYet),
+                Step::Done => {}
+            }
+        }
+
+        if this.matching.is_none() && !this.probe.done {
+            match ready!(this.probe.as_mut().poll_next(cx)) {
+                Step::Ready(item) => {
+                    let key = (this.key_b)(&item);
+                    if this.table.contains_key(&key) {
+                        *this.matching = Some((item, key, 0));
+                    } else if *this.kind == JoinKind::Outer {
+                        return Poll::Ready(Step::Ready(Joined::Right(item)));
+                    } else {
+                        return Poll::Ready(Step::NotYet);
+                    }
+                }
+                Step::NotYet => return Poll::Ready(Step::NotYet),
+                Step::Done => {}
+            }
+        }
+
+        if let Some((item, key, i)) = this.matching.take() {
+            let rows = &this.table[&key];
+            let (row, matched) = &mut this.rows[rows[i]];
+            *matched = true;
+            let row = row.clone();
+            let item = if i + 1 < rows.len() {
+                let next = item.clone();
+                *this.matching = Some((item, key, i + 1));
+                next
+            } else {
+                item
+            };
+            return Poll::Ready(Step::Ready(Joined::Both(row, item)));
+        }
+
+        if *this.kind == JoinKind::Inner {
+            return Poll::Ready(Step::Done);
+        }
+        let unmatched = this.unmatched.get_or_insert_with(|| {
+            *this.table = HashMap::new();
+            mem::take(this.rows).into_iter()
+        });
+        Poll::Ready(
+            unmatched
+                .find_map(|(row, matched)| (!matched).then_some(Joined::Left(row)))
+                .into(),
+        )
+    }
+}
@@ -0,0 +1,160 @@
+use alloc::collections::VecDeque;
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use pin_project_lite::pin_project;
+
+use super::{JoinKind, Joined};
+use crate::{
+    step::Step,
+    stream::{ext::StreamExt, fuse::Fuse, Stream},
+};
+
+/// Joins `left` and `right`, both sorted by key, on `key_a(a) == key_b(b)`.
+///
+/// Only the run of `right` items sharing the current key is buffered, which is joined with every
+/// `left` item of that key. Inner by default, see [`MergeJoin::kind()`].
+pub fn merge_join<A, B, K, FA, FB>(
+    left: A,
+    right: B,
+    key_a: FA,
+    key_b: FB,
+) -> MergeJoin<A, B, K, FA, FB>
+where
+    A: Stream,
+    B: Stream,
+    K: Ord,
+    FA: FnMut(&A::Item) -> K,
+    FB: FnMut(&B::Item) -> K,
+{
+    MergeJoin {
+        left: left.fuse(),
+        right: right.fuse(),
+        key_a,
+        key_b,
+        kind: JoinKind::Inner,
+        left_head: None,
+        right_head: None,
+        group: VecDeque::new(),
+        group_key: None,
+        group_complete: false,
+        joining: None,
+    }
+}
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct MergeJoin<A: Stream, B: Stream, K, FA, FB> {
+        #[pin]
+        left: Fuse<A>,
+        #[pin]
+        right: Fuse<B>,
+        key_a: FA,
+        key_b: FB,
+        kind: JoinKind,
+        left_head: Option<A::Item>,
+        right_head: Option<B::Item>,
+        // The run of right items sharing `group_key`.
+        group: VecDeque<B::Item>,
+        group_key: Option<K>,
+        group_complete: bool,
+        // The left item being joined with the group, and the next item of the group to join it with.
+        joining: Option<(A::Item, usize)>,
+    }
+}
+
+impl<A: Stream, B: Stream, K, FA, FB> MergeJoin<A, B, K, FA, FB> {
+    pub fn kind(mut self, kind: JoinKind) -> Self {
+        self.kind = kind;
+        self
+    }
+}
+
+impl<A, B, K, FA, FB> Stream for MergeJoin<A, B, K, FA, FB>
+where
+    A: Stream,
+    A::Item: Clone,
+    B: Stream,
+    B::Item: Clone,
+    K: Ord,
+    FA: FnMut(&A::Item) -> K,
+    FB: FnMut(&B::Item) -> K,
+{
+    type Item = Joined<A::Item, B::Item>;
+
+    const SYNC: bool = A::SYNC && B::SYNC;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            if let Some((item, i)) = this.joining.take() {
+                let right = this.group[i].clone();
+                let item = if i + 1 < this.group.len() {
+                    let next = item.clone();
+                    *this.joining = Some((item, i + 1));
+                    next
+                } else {
+                    item
+                };
+                return Poll::Ready(Step::Ready(Joined::Both(item, right)));
+            }
+
+            let left = this.left.as_mut().poll_head(this.left_head, cx);
+            let right = this.right.as_mut().poll_head(this.right_head, cx);
+            match (left, right) {
+                (Poll::Ready(Step::Ready(())), Poll::Ready(Step::Ready(()))) => {}
+                (Poll::Ready(Step::NotYet), _) | (_, Poll::Ready(Step::NotYet)) => {
+                    return Poll::Ready(Step::NotYet)
+                }
+                _ => return Poll::Pending,
+            }
+            let left_key = this.left_head.as_ref().map(&mut *this.key_a);
+            let right_key = this.right_head.as_ref().map(&mut *this.key_b);
+
+            if let Some(group_key) = this.group_key {
+                if !*this.group_complete {
+                    if right_key.as_ref() == Some(group_key) {
+                        this.group.push_back(this.right_head.take().unwrap());
+                        continue;
+                    }
+                    *this.group_complete = true;
+                }
+                if left_key.as_ref() == Some(group_key) {
+                    *this.joining = Some((this.left_head.take().unwrap(), 0));
+                } else {
+                    // A group only starts on a left item of its key, so it is always matched.
+                    this.group.clear();
+                    *this.group_key = None;
+                }
+                continue;
+            }
+
+            let left_first = match (&left_key, &right_key) {
+                (None, None) => return Poll::Ready(Step::Done),
+                (Some(left), Some(right)) if left == right => {
+                    *this.group_key = right_key;
+                    *this.group_complete = false;
+                    continue;
+                }
+                (Some(left), Some(right)) => left < right,
+                (left, _) => left.is_some(),
+            };
+            return Poll::Ready(if left_first {
+                let left = this.left_head.take().unwrap();
+                match this.kind {
+                    JoinKind::Inner => Step::NotYet,
+                    JoinKind::Left | JoinKind::Outer => Step::Ready(Joined::Left(left)),
+                }
+            } else {
+                let right = this.right_head.take().unwrap();
+                match this.kind {
+                    JoinKind::Inner | JoinKind::Left => Step::NotYet,
+                    JoinKind::Outer => Step::Ready(Joined::Right(right)),
+                }
+            });
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! Joins of two streams by key.
+
+pub mod hash_join;
+pub mod merge_join;
+
+pub use hash_join::hash_join;
+pub use merge_join::merge_join;
+
+/// Which unmatched items a join yields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JoinKind {
+    /// Only matched pairs.
+    #[default]
+    Inner,
+    /// Matched pairs and unmatched items of the left stream.
+    Left,
+    /// Matched pairs and unmatched items of both streams.
+    Outer,
+}
+
+/// An item of a join.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Joined<A, B> {
+    Both(A, B),
+    Left(A),
+    Right(B),
+}
+
+impl<A, B> Joined<A, B> {
+    /// Returns both sides, if any.
+    pub fn both(self) -> Option<(A, B)> {
+        match self {
+            Joined::Both(a, b) => Some((a, b)),
+            _ => None,
+        }
+    }
+
+    /// Splits into the optional left and right sides.
+    pub fn into_options(self) -> (Option<A>, Option<B>) {
+        match self {
+            Joined::Both(a, b) => (Some(a), Some(b)),
+            Joined::Left(a) => (Some(a), None),
+            Joined::Right(b) => (None, Some(b)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::vec::Vec;
+
+    use crate::stream::{ext::StreamExt, tests::drain, IntoFusion};
+
+    #[test]
+    fn join() {
+        use crate::stream::join::{hash_join, merge_join, JoinKind, Joined};
+
+        let left = [(1, 'a'), (2, 'b'), (2, 'c'), (4, 'd'), (6, 'e')];
+        let right = [(0, 'v'), (2, 'w'), (2, 'x'), (3, 'y'), (6, 'z'), (7, '!')];
+
+        // Nested loop join, ordered by key.
+        let expected = |kind| {
+            let mut joined = Vec::new();
+            for l in left {
+                let matches: Vec<_> = right.iter().filter(|r| r.0 == l.0).collect();
+                joined.extend(matches.iter().map(|r| Joined::Both(l, **r)));
+                if matches.is_empty() && kind != JoinKind::Inner {
+                    joined.push(Joined::Left(l));
+                }
+            }
+            if kind == JoinKind::Outer {
+                joined.extend(
+                    right
+                        .iter()
+                        .filter(|r| left.iter().all(|l| l.0 != r.0))
+                        .map(|r| Joined::Right(*r)),
+                );
+            }
+            let key = |j: &Joined<(i32, char), (i32, char)>| match j {
+                Joined::Both(l, _) | Joined::Left(l) => l.0,
+                Joined::Right(r) => r.0,
+            };
+            joined.sort_by_key(key);
+            joined
+        };
+
+        for kind in [JoinKind::Inner, JoinKind::Left, JoinKind::Outer] {
+            assert_eq!(
+                drain(
+                    merge_join(
+                        left.into_iter().into_fusion(),
+                        right.into_iter().into_fusion().filter(|_| true),
+                        |l| l.0,
+                        |r| r.0,
+                    )
+                    .kind(kind)
+                ),
+                expected(kind)
+            );
+
+            // Probe order, then the unmatched build items in the order they came.
+            let mut expected = Vec::new();
+            for r in right {
+                let matches: Vec<_> = left.iter().filter(|l| l.0 == r.0).collect();
+                expected.extend(matches.iter().map(|l| Joined::Both(**l, r)));
+                if matches.is_empty() && kind == JoinKind::Outer {
+                    expected.push(Joined::Right(r));
+                }
+            }
+            if kind != JoinKind::Inner {
+                expected.extend(
+                    left.iter()
+                        .filter(|l| right.iter().all(|r| r.0 != l.0))
+                        .map(|l| Joined::Left(*l)),
+                );
+            }
+            assert_eq!(
+                drain(
+                    hash_join(
+                        left.into_iter().into_fusion(),
+                        right.into_iter().into_fusion(),
+                        |l| l.0,
+                        |r| r.0,
+                    )
+                    .kind(kind)
+                ),
+                expected
+            );
+        }
+
+        // Unmatched build items keep their order, whatever their hashes.
+        let unmatched = drain(
+            hash_join(
+                (0..64).rev().into_fusion(),
+                core::iter::empty::<i32>().into_fusion(),
+                |l| *l,
+                |r| *r,
+            )
+            .kind(JoinKind::Left),
+        );
+        assert_eq!(
+            unmatched,
+            (0..64).rev().map(Joined::Left).collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,169 @@
+use alloc::collections::BTreeMap;
+use core::{
+    hash::Hash,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use hashbrown::HashMap;
+use pin_project_lite::pin_project;
+
+use super::Stream;
+use crate::{ready_step, step::Step};
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct KeyedProcess<S, K, T, I: IntoIterator, KF, IF, F> {
+        #[pin]
+        pub(super) stream: S,
+        pub(super) key_fn: KF,
+        pub(super) init: IF,
+        pub(super) f: F,
+        // The state of every key along with the tick of its last item.
+        pub(super) states: HashMap<K, (T, u64)>,
+        pub(super) outputs: Option<I::IntoIter>,
+        pub(super) tick: u64,
+        pub(super) ttl: Option<u64>,
+        // Every key with a state by the tick of its last item, oldest first. Only kept with a TTL.
+        pub(super) expiry: BTreeMap<u64, K>,
+    }
+}
+
+impl<S, K, T, I: IntoIterator, KF, IF, F> KeyedProcess<S, K, T, I, KF, IF, F> {
+    /// Drops the state of a key once more than `ttl` items have passed since its last one. The
+    /// next item of that key starts over from `init`.
+    ///
+    /// The TTL counts items of the stream, of any key, not time.
+    pub fn ttl(mut self, ttl: u64) -> Self {
+        assert!(ttl > 0, "`ttl` must be greater than zero");
+        self.ttl = Some(ttl);
+        self
+    }
+}
+
+impl<S, K, T, I, KF, IF, F> Stream for KeyedProcess<S, K, T, I, KF, IF, F>
+where
+    S: Stream,
+    K: Eq + Hash + Clone,
+    I: IntoIterator,
+    KF: FnMut(&S::Item) -> K,
+    IF: FnMut() -> T,
+    F: FnMut(&mut T, S::Item) -> I,
+{
+    type Item = I::Item;
+
+    const SYNC: bool = S::SYNC;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let this = self.project();
+
+        if let Some(output) = this.outputs.as_mut().and_then(Iterator::next) {
+            return Poll::Ready(Step::Ready(output));
+        }
+        *this.outputs = None;
+
+        let item = ready_step!(this.stream.poll_next(cx));
+        *this.tick += 1;
+        let tick = *this.tick;
+        if let Some(ttl) = *this.ttl {
+            while let Some(expired) = this.expiry.first_entry() {
+                if tick - *expired.key() <= ttl {
+                    break;
+                }
+                this.states.remove(&expired.remove());
+            }
+        }
+
+        let key = (this.key_fn)(&item);
+        if this.ttl.is_some() {
+            if let Some((_, last)) = this.states.get(&key) {
+                this.expiry.remove(last);
+            }
+            this.expiry.insert(tick, key.clone());
+        }
+        let (state, last) = this.states.entry(key).or_insert_with(|| ((this.init)(), 0));
+        *last = tick;
+        let mut outputs = (this.f)(state, item).into_iter();
+
+        Poll::Ready(match outputs.next() {
+            Some(output) => {
+                *this.outputs = Some(outputs);
+                Step::Ready(output)
+            }
+            None => Step::NotYet,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self
+            .outputs
+            .as_ref()
+            .map_or((0, Some(0)), Iterator::size_hint);
+        match self.stream.size_hint() {
+            (_, Some(0)) => (lower, upper),
+            _ => (lower, None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use core::{
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    use crate::{
+        step::Step,
+        stream::{ext::StreamExt, tests::drain, IntoFusion, Stream},
+    };
+
+    #[test]
+    fn keyed_process() {
+        let keys = ['a', 'b', 'a', 'c', 'd', 'a'];
+        let counts = |ttl: Option<u64>| {
+            let stream = keys.into_iter().into_fusion().keyed_process(
+                |k| *k,
+                || 0,
+                |count, _| {
+                    *count += 1;
+                    Some(*count)
+                },
+            );
+            drain(match ttl {
+                Some(ttl) => stream.ttl(ttl),
+                None => stream,
+            })
+        };
+        assert_eq!(counts(None), [1, 1, 2, 1, 1, 3]);
+        // The last `a` comes more than two items after the previous one.
+        assert_eq!(counts(Some(2)), [1, 1, 2, 1, 1, 1]);
+        assert_eq!(counts(Some(3)), [1, 1, 2, 1, 1, 3]);
+        assert_eq!(counts(Some(u64::MAX)), [1, 1, 2, 1, 1, 3]);
+
+        // Only the last item of every key is kept track of for the TTL.
+        let mut stream = pin!(['a', 'b']
+            .into_iter()
+            .cycle()
+            .take(1000)
+            .into_fusion()
+            .keyed_process(|k| *k, || (), |_, _| None::<()>)
+            .ttl(500));
+        let mut cx = Context::from_waker(Waker::noop());
+        while stream.as_mut().poll_next(&mut cx) != Poll::Ready(Step::Done) {
+            assert!(stream.expiry.len() <= 2);
+        }
+
+        assert_eq!(
+            drain(
+                [2, 0, 1]
+                    .into_iter()
+                    .into_fusion()
+                    .keyed_process(|_| (), || (), |_, n| 0..n)
+            ),
+            [0, 1, 0]
+        );
+    }
+}
@@ -0,0 +1,211 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    cmp::Ordering,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use super::Stream;
+use crate::step::Step;
+
+/// Merges streams that are each sorted by `cmp` into one sorted stream.
+///
+/// Every stream is only advanced once its previous head has been emitted, so a head can only be
+/// emitted after all streams that are not done hold one. Equal items are emitted in the order of
+/// their streams in `streams`.
+pub fn kmerge_by<I, F>(streams: I, cmp: F) -> KMergeBy<I::Item, F>
+where
+    I: IntoIterator,
+    I::Item: Stream,
+    F: FnMut(&<I::Item as Stream>::Item, &<I::Item as Stream>::Item) -> Ordering,
+{
+    let streams: Vec<_> = streams.into_iter().map(|s| Some(Box::pin(s))).collect();
+    KMergeBy {
+        waiting: (0..streams.len()).collect(),
+        heap: Vec::with_capacity(streams.len()),
+        streams,
+        cmp,
+    }
+}
+
+#[must_use = "streams do nothing unless polled"]
+pub struct KMergeBy<S: Stream, F> {
+    streams: Vec<Option<Pin<Box<S>>>>,
+    /// Streams whose head has been emitted and that have to be polled for the next one.
+    waiting: Vec<usize>,
+    /// Heads of the other streams, as a binary min-heap ordered by `cmp` and then by stream.
+    heap: Vec<(S::Item, usize)>,
+    cmp: F,
+}
+
+impl<S: Stream, F> Unpin for KMergeBy<S, F> {}
+
+impl<S, F> Stream for KMergeBy<S, F>
+where
+    S: Stream,
+    F: FnMut(&S::Item, &S::Item) -> Ordering,
+{
+    type Item = S::Item;
+
+    const SYNC: bool = S::SYNC;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let this = self.get_mut();
+
+        let mut not_yet = false;
+        let mut i = 0;
+        while let Some(&index) = this.waiting.get(i) {
+            let slot = &mut this.streams[index];
+            let Some(stream) = slot else {
+                unreachable!("finished streams are never waited on")
+            };
+            match stream.as_mut().poll_next(cx) {
+                Poll::Ready(Step::Ready(item)) => {
+                    this.waiting.swap_remove(i);
+                    push(&mut this.heap, (item, index), &mut this.cmp);
+                }
+                Poll::Ready(Step::Done) => {
+                    this.waiting.swap_remove(i);
+                    *slot = None;
+                }
+                Poll::Ready(Step::NotYet) => {
+                    not_yet = true;
+                    i += 1;
+                }
+                Poll::Pending => i += 1,
+            }
+        }
+        if !this.waiting.is_empty() {
+            return if not_yet {
+                Poll::Ready(Step::NotYet)
+            } else {
+                Poll::Pending
+            };
+        }
+
+        Poll::Ready(match pop(&mut this.heap, &mut this.cmp) {
+            Some((item, index)) => {
+                this.waiting.push(index);
+                Step::Ready(item)
+            }
+            None => Step::Done,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let heads = self.heap.len();
+        self.streams
+            .iter()
+            .flatten()
+            .map(|stream| stream.size_hint())
+            .fold((heads, Some(heads)), |(lower, upper), (l, u)| {
+                (
+                    lower.saturating_add(l),
+                    upper.zip(u).and_then(|(upper, u)| upper.checked_add(u)),
+                )
+            })
+    }
+}
+
+fn less<T, F>(a: &(T, usize), b: &(T, usize), cmp: &mut F) -> bool
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    cmp(&a.0, &b.0).then(a.1.cmp(&b.1)) == Ordering::Less
+}
+
+fn push<T, F>(heap: &mut Vec<(T, usize)>, head: (T, usize), cmp: &mut F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    heap.push(head);
+    let mut child = heap.len() - 1;
+    while child > 0 {
+        let parent = (child - 1) / 2;
+        if !less(&heap[child], &heap[parent], cmp) {
+            break;
+        }
+        heap.swap(child, parent);
+        child = parent;
+    }
+}
+
+fn pop<T, F>(heap: &mut Vec<(T, usize)>, cmp: &mut F) -> Option<(T, usize)>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    if heap.is_empty() {
+        return None;
+    }
+    let last = heap.len() - 1;
+    heap.swap(0, last);
+    let min = heap.pop();
+    let mut parent = 0;
+    loop {
+        let left = 2 * parent + 1;
+        let right = left + 1;
+        let mut smallest = parent;
+        if left < heap.len() && less(&heap[left], &heap[smallest], cmp) {
+            smallest = left;
+        }
+        if right < heap.len() && less(&heap[right], &heap[smallest], cmp) {
+            smallest = right;
+        }
+        if smallest == parent {
+            break;
+        }
+        heap.swap(parent, smallest);
+        parent = smallest;
+    }
+    min
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::vec::Vec;
+
+    use crate::stream::{
+        ext::StreamExt,
+        tests::{assert_size_hints, drain},
+    };
+
+    #[test]
+    fn kmerge() {
+        use crate::stream::{kmerge_by, source};
+
+        let segments = [
+            std::vec![1, 4, 7, 7, 9],
+            std::vec![],
+            std::vec![0, 2, 7, 10],
+            std::vec![3, 5],
+        ];
+        let mut sorted: Vec<_> = segments.iter().flatten().copied().collect();
+        sorted.sort();
+        assert_size_hints(
+            kmerge_by(segments.clone().map(source::iter), Ord::cmp),
+            sorted.clone().into_iter(),
+        );
+        assert_eq!(
+            drain(kmerge_by(
+                segments.map(|s| source::iter(s).filter(|i| i % 2 == 0 || *i > 5)),
+                |a, b| a.cmp(b)
+            )),
+            sorted
+                .into_iter()
+                .filter(|i| i % 2 == 0 || *i > 5)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            drain(kmerge_by(
+                [
+                    source::iter(std::vec![3, 2, 1]),
+                    source::iter(std::vec![4, 0])
+                ],
+                |a: &i32, b| b.cmp(a)
+            )),
+            [4, 3, 2, 1, 0]
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use core::{
+    ops::ControlFlow,
     pin::Pin,
     task::{Context, Poll},
 };
@@ -6,7 +7,7 @@ use core::{
 use futures_core::ready;
 use pin_project_lite::pin_project;
 
-use super::Stream;
+use super::{DoubleEndedStream, ExactSizeStream, Stream};
 use crate::step::Step;
 
 pin_project! {
@@ -24,6 +25,8 @@ where
 {
     type Item = T;
 
+    const SYNC: bool = S::SYNC;
+
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
         let this = self.project();
         let next = ready!(this.stream.poll_next(cx));
@@ -33,4 +36,40 @@ where
     fn size_hint(&self) -> (usize, Option<usize>) {
         self.stream.size_hint()
     }
+
+    #[inline]
+    fn poll_fold_n<B, G>(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        budget: &mut usize,
+        acc: &mut B,
+        mut g: G,
+    ) -> Poll<Step<()>>
+    where
+        G: FnMut(&mut B, Self::Item) -> ControlFlow<()>,
+    {
+        let this = self.project();
+        let f = this.f;
+        this.stream
+            .poll_fold_n(cx, budget, acc, |acc, item| g(acc, f(item)))
+    }
+}
+
+impl<S, F, T> ExactSizeStream for Map<S, F>
+where
+    S: ExactSizeStream,
+    F: FnMut(S::Item) -> T,
+{
+}
+
+impl<S, F, T> DoubleEndedStream for Map<S, F>
+where
+    S: DoubleEndedStream,
+    F: FnMut(S::Item) -> T,
+{
+    fn poll_next_back(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let this = self.project();
+        let next = ready!(this.stream.poll_next_back(cx));
+        Poll::Ready(next.map(this.f))
+    }
 }
@@ -41,7 +41,7 @@ where
                 match ready!(this.stream.as_mut().poll_next(cx)) {
                     Step::NotYet => return Poll::Ready(Step::NotYet),
                     Step::Ready(item) => this.future.set(Some((this.f)(item))),
-                    Step::Done => return Poll::Ready(Step::NotYet),
+                    Step::Done => return Poll::Ready(Step::Done),
                 }
             }
         }
@@ -55,3 +55,59 @@ where
         (lower, upper)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use core::{
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+    use std::vec::Vec;
+
+    use crate::{
+        consumer::ConsumerExt,
+        step::Step,
+        stream::{ext::StreamExt, IntoFusion, Stream},
+    };
+
+    #[test]
+    fn map_async() {
+        let mut stream = pin!((0..1).into_fusion().map_async(core::future::ready));
+        let mut cx = Context::from_waker(Waker::noop());
+        assert!(matches!(
+            stream.as_mut().poll_next(&mut cx),
+            Poll::Ready(Step::Ready(0))
+        ));
+        // The stream ends with its source.
+        assert!(matches!(
+            stream.as_mut().poll_next(&mut cx),
+            Poll::Ready(Step::Done)
+        ));
+
+        // Every future is pending once before resolving.
+        let doubled = |i: i32| {
+            let mut polled = false;
+            core::future::poll_fn(move |cx| {
+                if polled {
+                    Poll::Ready(i * 2)
+                } else {
+                    polled = true;
+                    cx.waker().wake_by_ref();
+                    Poll::Pending
+                }
+            })
+        };
+        for step in [1, 3, 32] {
+            let collected: Vec<_> = spin_on::spin_on(
+                (0..5)
+                    .into_fusion()
+                    .map_async(doubled)
+                    .collect()
+                    .yield_by(step),
+            );
+            assert_eq!(collected, std::vec![0, 2, 4, 6, 8]);
+        }
+    }
+}
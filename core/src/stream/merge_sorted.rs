@@ -0,0 +1,119 @@
+use core::{
+    cmp::Ordering,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use pin_project_lite::pin_project;
+
+use super::{fuse::Fuse, Stream};
+use crate::step::Step;
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct MergeSortedBy<S: Stream, U, F> {
+        #[pin]
+        pub(super) first: Fuse<S>,
+        #[pin]
+        pub(super) second: Fuse<U>,
+        pub(super) first_head: Option<S::Item>,
+        pub(super) second_head: Option<S::Item>,
+        pub(super) cmp: F,
+    }
+}
+
+impl<S, U, F> Stream for MergeSortedBy<S, U, F>
+where
+    S: Stream,
+    U: Stream<Item = S::Item>,
+    F: FnMut(&S::Item, &S::Item) -> Ordering,
+{
+    type Item = S::Item;
+
+    const SYNC: bool = S::SYNC && U::SYNC;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let this = self.project();
+
+        // Both heads have to be known before either can be emitted, so poll both sides before
+        // reporting that one of them is not ready.
+        let first = this.first.poll_head(this.first_head, cx);
+        let second = this.second.poll_head(this.second_head, cx);
+        match (first, second) {
+            (Poll::Ready(Step::Ready(())), Poll::Ready(Step::Ready(()))) => {}
+            (Poll::Ready(Step::NotYet), _) | (_, Poll::Ready(Step::NotYet)) => {
+                return Poll::Ready(Step::NotYet)
+            }
+            _ => return Poll::Pending,
+        }
+
+        let next = match (this.first_head.as_ref(), this.second_head.as_ref()) {
+            (Some(first), Some(second)) if (this.cmp)(first, second) == Ordering::Greater => {
+                this.second_head.take()
+            }
+            (Some(_), _) => this.first_head.take(),
+            (None, _) => this.second_head.take(),
+        };
+        Poll::Ready(next.into())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let heads = self.first_head.is_some() as usize + self.second_head.is_some() as usize;
+        let (first_lower, first_upper) = self.first.size_hint();
+        let (second_lower, second_upper) = self.second.size_hint();
+        let lower = first_lower
+            .saturating_add(second_lower)
+            .saturating_add(heads);
+        let upper = match (first_upper, second_upper) {
+            (Some(first), Some(second)) => first
+                .checked_add(second)
+                .and_then(|upper| upper.checked_add(heads)),
+            _ => None,
+        };
+        (lower, upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::stream::{
+        ext::StreamExt,
+        tests::{assert_size_hints, drain},
+        IntoFusion,
+    };
+
+    #[test]
+    fn merge_sorted() {
+        assert_size_hints(
+            (0..10)
+                .into_fusion()
+                .step_by(3)
+                .merge_sorted_by((0..10).into_fusion().step_by(2), Ord::cmp),
+            [0, 0, 2, 3, 4, 6, 6, 8, 9].into_iter(),
+        );
+        // Ties favour `self`, and rejections on one side hold the other one back.
+        assert_eq!(
+            drain(
+                (0..20)
+                    .into_fusion()
+                    .filter(|i| i % 5 == 0)
+                    .map(|i| (i, 'a'))
+                    .merge_sorted_by((0..4).into_fusion().map(|i| (i * 5, 'b')), |a, b| {
+                        a.0.cmp(&b.0)
+                    })
+            ),
+            [
+                (0, 'a'),
+                (0, 'b'),
+                (5, 'a'),
+                (5, 'b'),
+                (10, 'a'),
+                (10, 'b'),
+                (15, 'a'),
+                (15, 'b')
+            ]
+        );
+    }
+}
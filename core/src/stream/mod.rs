@@ -1,27 +1,65 @@
 pub mod chain;
+#[cfg(feature = "alloc")]
+pub mod chunk_by;
 pub mod cloned;
+pub(crate) mod coalesce;
 pub mod copied;
+pub mod dedup;
 pub mod ext;
 pub mod filter;
 pub mod flat_map;
 pub mod flatten;
 pub mod fuse;
+#[cfg(feature = "alloc")]
+pub mod generator;
+#[cfg(feature = "alloc")]
+pub mod group_by;
+pub mod interleave;
+#[cfg(feature = "alloc")]
+pub mod join;
+#[cfg(feature = "alloc")]
+pub mod keyed_process;
+#[cfg(feature = "alloc")]
+pub mod kmerge;
 pub mod map;
 pub mod map_async;
+pub mod merge_sorted;
 pub mod next;
+pub mod rate_limit;
+pub mod rev;
+pub mod round_robin;
+pub mod sample;
+pub mod select;
+#[cfg(feature = "alloc")]
+pub mod select_all;
 pub mod skip;
 pub mod skip_while;
+pub mod source;
 pub mod step_by;
+pub(crate) mod sync_iter;
 pub mod take;
 pub mod take_while;
 pub mod try_next;
+#[cfg(feature = "alloc")]
+pub mod unique;
+pub mod watermark;
+#[cfg(feature = "alloc")]
+pub mod window;
 
 use core::{
+    ops::ControlFlow,
     pin::Pin,
     task::{Context, Poll},
 };
 
+use futures_core::ready;
+#[cfg(feature = "alloc")]
+pub use kmerge::kmerge_by;
 use pin_project_lite::pin_project;
+pub use round_robin::round_robin;
+pub use select::{merge, select_with_strategy, Strategy};
+#[cfg(feature = "alloc")]
+pub use select_all::select_all;
 
 use crate::step::Step;
 
@@ -29,11 +67,56 @@ use crate::step::Step;
 pub trait Stream {
     type Item;
 
+    /// Whether the stream is guaranteed to never return [`Poll::Pending`].
+    ///
+    /// Consumers drive such streams through a plain [`Iterator`] loop instead of polling them.
+    const SYNC: bool = false;
+
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>>;
 
     fn size_hint(&self) -> (usize, Option<usize>) {
         (0, None)
     }
+
+    /// Drives the stream for at most `budget` steps, feeding every ready item to `f` along with
+    /// `acc`.
+    ///
+    /// `budget` is decreased by the number of steps taken, [`Step::NotYet`] included. Resolves to
+    /// [`Step::Ready`] if `f` breaks, to [`Step::NotYet`] if the budget runs out and to
+    /// [`Step::Done`] once the stream is exhausted.
+    ///
+    /// Adapters override this to process many items per call instead of going through
+    /// [`poll_next()`][`Stream::poll_next()`] for each of them. Those dropping items, like
+    /// [`filter()`][`ext::StreamExt::filter()`] or [`skip()`][`ext::StreamExt::skip()`], step
+    /// over them here instead of surfacing a [`Step::NotYet`] for each, every dropped item still
+    /// taking a step of `budget` unless they are told to
+    /// [`coalesce()`][`filter::Filter::coalesce()`] them.
+    #[inline]
+    fn poll_fold_n<B, F>(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        budget: &mut usize,
+        acc: &mut B,
+        mut f: F,
+    ) -> Poll<Step<()>>
+    where
+        F: FnMut(&mut B, Self::Item) -> ControlFlow<()>,
+    {
+        while *budget > 0 {
+            let next = ready!(self.as_mut().poll_next(cx));
+            *budget -= 1;
+            match next {
+                Step::NotYet => {}
+                Step::Ready(item) => {
+                    if f(acc, item).is_break() {
+                        return Poll::Ready(Step::Ready(()));
+                    }
+                }
+                Step::Done => return Poll::Ready(Step::Done),
+            }
+        }
+        Poll::Ready(Step::NotYet)
+    }
 }
 
 impl<S: futures_core::Stream> Stream for S {
@@ -51,6 +134,29 @@ impl<S: futures_core::Stream> Stream for S {
     }
 }
 
+/// A stream able to yield items from both ends.
+pub trait DoubleEndedStream: Stream {
+    fn poll_next_back(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>>;
+}
+
+/// A stream that knows its exact length.
+///
+/// Implementors must report the exact number of remaining items through
+/// [`size_hint()`][`Stream::size_hint()`].
+pub trait ExactSizeStream: Stream {
+    #[inline]
+    fn len(&self) -> usize {
+        let (lower, upper) = self.size_hint();
+        assert_eq!(upper, Some(lower));
+        lower
+    }
+
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
 pub trait IntoFusion {
     type Stream: Stream;
 
@@ -73,6 +179,8 @@ impl<I: Iterator> From<I> for IteratorStream<I> {
 impl<I: Iterator> Stream for IteratorStream<I> {
     type Item = I::Item;
 
+    const SYNC: bool = true;
+
     fn poll_next(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Step<Self::Item>> {
         Poll::Ready(match self.project().iterator.next() {
             Some(item) => Step::Ready(item),
@@ -83,8 +191,48 @@ impl<I: Iterator> Stream for IteratorStream<I> {
     fn size_hint(&self) -> (usize, Option<usize>) {
         self.iterator.size_hint()
     }
+
+    #[inline]
+    fn poll_fold_n<B, F>(
+        self: Pin<&mut Self>,
+        _: &mut Context<'_>,
+        budget: &mut usize,
+        acc: &mut B,
+        mut f: F,
+    ) -> Poll<Step<()>>
+    where
+        F: FnMut(&mut B, Self::Item) -> ControlFlow<()>,
+    {
+        let mut remaining = *budget;
+        let flow = self
+            .project()
+            .iterator
+            .by_ref()
+            .take(*budget)
+            .try_for_each(|item| {
+                remaining -= 1;
+                f(acc, item)
+            });
+        *budget = remaining;
+        Poll::Ready(match flow {
+            ControlFlow::Break(()) => Step::Ready(()),
+            ControlFlow::Continue(()) if remaining > 0 => Step::Done,
+            ControlFlow::Continue(()) => Step::NotYet,
+        })
+    }
+}
+
+impl<I: DoubleEndedIterator> DoubleEndedStream for IteratorStream<I> {
+    fn poll_next_back(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        Poll::Ready(match self.project().iterator.next_back() {
+            Some(item) => Step::Ready(item),
+            None => Step::Done,
+        })
+    }
 }
 
+impl<I: ExactSizeIterator> ExactSizeStream for IteratorStream<I> {}
+
 impl<I: Iterator> IntoFusion for I {
     type Stream = IteratorStream<I>;
 
@@ -92,3 +240,348 @@ impl<I: Iterator> IntoFusion for I {
         self.into()
     }
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    extern crate std;
+
+    use core::{
+        fmt::Debug,
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+    use std::vec::Vec;
+
+    use super::{ext::StreamExt, DoubleEndedStream, ExactSizeStream, IntoFusion, Stream};
+    use crate::{consumer::ConsumerExt, step::Step};
+
+    /// Drains `stream` and `iter` side by side, checking before every item that the hint of
+    /// `stream` holds the number of remaining items and is at least as tight as the one of `iter`.
+    pub(crate) fn assert_size_hints<S, I>(stream: S, mut iter: I)
+    where
+        S: Stream,
+        I: Iterator<Item = S::Item> + Clone,
+        S::Item: PartialEq + Debug,
+    {
+        let mut stream = pin!(stream);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            let (lower, upper) = stream.size_hint();
+            let (iter_lower, iter_upper) = iter.size_hint();
+            let remaining = iter.clone().count();
+            assert!(iter_lower <= lower && lower <= remaining);
+            assert!(upper.is_none_or(|upper| remaining <= upper));
+            assert!(iter_upper.is_none_or(|iter_upper| upper.is_some_and(|u| u <= iter_upper)));
+
+            let next = loop {
+                match stream.as_mut().poll_next(&mut cx) {
+                    Poll::Ready(Step::NotYet) => {}
+                    Poll::Ready(Step::Ready(item)) => break Some(item),
+                    Poll::Ready(Step::Done) => break None,
+                    Poll::Pending => unreachable!(),
+                }
+            };
+            let expect = iter.next();
+            assert_eq!(next, expect);
+            if expect.is_none() {
+                break;
+            }
+        }
+    }
+
+    /// Collects `stream`, yielding back to the executor after every step.
+    pub(crate) fn drain<S: Stream>(stream: S) -> Vec<S::Item> {
+        spin_on::spin_on(stream.collect().yield_by(1))
+    }
+
+    /// Polls `stream` to the end, returning every step it took.
+    pub(crate) fn steps<S: Stream>(stream: S) -> Vec<Step<S::Item>> {
+        let mut stream = pin!(stream);
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut steps = Vec::new();
+        loop {
+            match stream.as_mut().poll_next(&mut cx) {
+                Poll::Ready(Step::Done) => break steps,
+                Poll::Ready(step) => steps.push(step),
+                Poll::Pending => unreachable!(),
+            }
+        }
+    }
+
+    /// The items of `0..n`, from a stream that is not [`Stream::SYNC`], so consumers fold it
+    /// through `poll_fold_n()` rather than a `SyncIter`.
+    pub(crate) fn polled(n: u64) -> impl Stream<Item = u64> {
+        let mut range = 0..n;
+        super::source::from_fn(move |_| Poll::Ready(range.next().map_or(Step::Done, Step::Ready)))
+    }
+
+    #[test]
+    fn size_hint() {
+        let slice = [3, 1, 4, 1, 5, 9, 2, 6];
+
+        assert_size_hints((0..10).into_fusion().map(|i| i * 2), (0..10).map(|i| i * 2));
+        assert_size_hints(
+            (0..10).into_fusion().filter(|i| i % 3 == 0),
+            (0..10).filter(|i| i % 3 == 0),
+        );
+        assert_size_hints(
+            (0..4).into_fusion().chain((10..13).into_fusion()),
+            (0..4).chain(10..13),
+        );
+        assert_size_hints(
+            [0..3, 3..3, 7..9]
+                .into_iter()
+                .map(IntoFusion::into_fusion)
+                .into_fusion()
+                .flatten(),
+            [0..3, 3..3, 7..9].into_iter().flatten(),
+        );
+        assert_size_hints(
+            (0..4).into_fusion().flat_map(|i| (0..i).into_fusion()),
+            (0..4).flat_map(|i| 0..i),
+        );
+        assert_size_hints((0..5).into_fusion().fuse(), (0..5).fuse());
+        assert_size_hints((0..10).into_fusion().skip(3), (0..10).skip(3));
+        assert_size_hints((0..3).into_fusion().skip(5), (0..3).skip(5));
+        assert_size_hints((0..10).into_fusion().take(3), (0..10).take(3));
+        assert_size_hints((0..3).into_fusion().take(5), (0..3).take(5));
+        assert_size_hints(
+            (0..10).into_fusion().take_while(|i| *i < 4),
+            (0..10).take_while(|i| *i < 4),
+        );
+        assert_size_hints(
+            (0..10).into_fusion().skip_while(|i| *i < 4),
+            (0..10).skip_while(|i| *i < 4),
+        );
+        for step in 1..5 {
+            assert_size_hints((0..10).into_fusion().step_by(step), (0..10).step_by(step));
+        }
+        assert_size_hints(slice.iter().into_fusion().cloned(), slice.iter().cloned());
+        assert_size_hints(slice.iter().into_fusion().copied(), slice.iter().copied());
+        assert_size_hints((0..10).into_fusion().rev(), (0..10).rev());
+        assert_size_hints(
+            (0..10).into_fusion().take(7).skip(2).rev(),
+            (0..10).take(7).skip(2).rev(),
+        );
+    }
+
+    #[test]
+    fn exact_size() {
+        let stream = (0..10)
+            .into_fusion()
+            .step_by(3)
+            .map(|i| i + 1)
+            .skip(1)
+            .take(2);
+        assert_eq!(
+            stream.len(),
+            (0..10).step_by(3).map(|i| i + 1).skip(1).take(2).len()
+        );
+    }
+
+    #[test]
+    fn poll_fold_n() {
+        let expect = (0..2048)
+            .step_by(3)
+            .map(|i| i * 7)
+            .filter(|i| i % 4 != 0)
+            .take(200)
+            .fold(0, |acc, i| acc ^ i);
+        let expect_count = (0..2048).skip(5).filter(|i| i % 3 == 0).take(300).count();
+        for step in [1, 2, 7, 4096] {
+            let n = spin_on::spin_on(
+                (0..2048)
+                    .into_fusion()
+                    .step_by(3)
+                    .map(|i| i * 7)
+                    .filter(|i| i % 4 != 0)
+                    .take(200)
+                    .fold(0, |acc, i| acc ^ i)
+                    .yield_by(step),
+            );
+            assert_eq!(n, expect);
+            let n = spin_on::spin_on(
+                polled(2048)
+                    .step_by(3)
+                    .map(|i| i * 7)
+                    .filter(|i| i % 4 != 0)
+                    .take(200)
+                    .fold(0, |acc, i| acc ^ i)
+                    .yield_by(step),
+            );
+            assert_eq!(n, expect);
+
+            for count in [
+                spin_on::spin_on(
+                    (0..2048)
+                        .into_fusion()
+                        .skip(5)
+                        .filter(|i| i % 3 == 0)
+                        .take(300)
+                        .count()
+                        .yield_by(step),
+                ),
+                spin_on::spin_on(
+                    polled(2048)
+                        .skip(5)
+                        .filter(|i| i % 3 == 0)
+                        .take(300)
+                        .count()
+                        .yield_by(step),
+                ),
+            ] {
+                assert_eq!(count, expect_count);
+            }
+
+            let found = spin_on::spin_on(
+                (0..2048)
+                    .into_fusion()
+                    .map(|i| i * 7)
+                    .find(|i| i % 1001 == 0 && *i > 0)
+                    .yield_by(step),
+            );
+            assert_eq!(
+                found,
+                (0..2048).map(|i| i * 7).find(|i| i % 1001 == 0 && *i > 0)
+            );
+        }
+    }
+
+    #[test]
+    fn double_ended() {
+        let slice = [3, 1, 4, 1, 5, 9, 2, 6];
+
+        assert_eq!(
+            drain(
+                (0..10)
+                    .into_fusion()
+                    .map(|i| i * 2)
+                    .filter(|i| i % 3 != 0)
+                    .rev()
+            ),
+            (0..10)
+                .map(|i| i * 2)
+                .filter(|i| i % 3 != 0)
+                .rev()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            drain((0..3).into_fusion().chain((10..13).into_fusion()).rev()),
+            (0..3).chain(10..13).rev().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            drain(slice.iter().into_fusion().copied().skip(2).take(4).rev()),
+            slice
+                .iter()
+                .copied()
+                .skip(2)
+                .take(4)
+                .rev()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            drain(slice.iter().into_fusion().cloned().take(20).skip(6).rev()),
+            slice
+                .iter()
+                .cloned()
+                .take(20)
+                .skip(6)
+                .rev()
+                .collect::<Vec<_>>()
+        );
+
+        // Forwards, `first` is polled once per step until done, then `second` takes over.
+        let mut first_polls = 0;
+        let mut first = 0..3;
+        let first = super::source::from_fn(|_| {
+            first_polls += 1;
+            Poll::Ready(first.next().map_or(Step::Done, Step::Ready))
+        });
+        let mut chain = pin!(first.chain((10..12).into_fusion()));
+        let mut cx = Context::from_waker(Waker::noop());
+        let polled: Vec<_> = (0..7).map(|_| chain.as_mut().poll_next(&mut cx)).collect();
+        assert_eq!(
+            polled,
+            [0, 1, 2, 10, 11]
+                .map(|i| Poll::Ready(Step::Ready(i)))
+                .into_iter()
+                .chain([Poll::Ready(Step::Done), Poll::Ready(Step::Done)])
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(first_polls, 4);
+
+        // Both ends of a chain meet in the middle.
+        let mut chain = pin!((0..3).into_fusion().chain((10..12).into_fusion()));
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut polled = Vec::new();
+        for back in [true, false, true, true, false, true] {
+            let next = if back {
+                chain.as_mut().poll_next_back(&mut cx)
+            } else {
+                chain.as_mut().poll_next(&mut cx)
+            };
+            polled.push(next);
+        }
+        assert_eq!(
+            polled,
+            [11, 0, 10, 2, 1]
+                .map(|i| Poll::Ready(Step::Ready(i)))
+                .into_iter()
+                .chain([Poll::Ready(Step::Done)])
+                .collect::<Vec<_>>()
+        );
+
+        for step in [1, 32] {
+            let run = |f: fn(Vec<i32>, i32) -> Vec<i32>| {
+                spin_on::spin_on(
+                    slice
+                        .iter()
+                        .copied()
+                        .into_fusion()
+                        .take(6)
+                        .rfold(Vec::new(), f)
+                        .yield_by(step),
+                )
+            };
+            let push = |mut acc: Vec<i32>, i| {
+                acc.push(i);
+                acc
+            };
+            assert_eq!(
+                run(push),
+                slice.iter().copied().take(6).rfold(Vec::new(), push)
+            );
+
+            let rfind = |n: i32| {
+                spin_on::spin_on(
+                    slice
+                        .iter()
+                        .into_fusion()
+                        .skip(1)
+                        .rfind(|i| **i < n)
+                        .yield_by(step),
+                )
+            };
+            for n in [0, 2, 5, 10] {
+                assert_eq!(rfind(n), slice.iter().skip(1).rfind(|i| **i < n));
+            }
+
+            let last = spin_on::spin_on(
+                (0..10)
+                    .into_fusion()
+                    .filter(|i| i % 4 == 1)
+                    .take_while(|i| *i < 7)
+                    .last()
+                    .yield_by(step),
+            );
+            assert_eq!(
+                last,
+                (0..10).filter(|i| i % 4 == 1).take_while(|i| *i < 7).last()
+            );
+            let last = spin_on::spin_on((0..7).into_fusion().take(3).last_back().yield_by(step));
+            assert_eq!(last, (0..7).take(3).next_back());
+            let last = spin_on::spin_on((0..0).into_fusion().last().yield_by(step));
+            assert_eq!(last, None);
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use futures_core::ready;
+use pin_project_lite::pin_project;
+
+use super::Stream;
+use crate::{step::Step, time::Timer};
+
+/// The stream returned by [`throttle()`][`super::ext::StreamExt::throttle()`].
+pub type Throttle<S, T> = RateLimit<S, T>;
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct RateLimit<S: Stream, T> {
+        #[pin]
+        pub(super) stream: S,
+        pub(super) timer: T,
+        pub(super) capacity: u32,
+        pub(super) refill: Duration,
+        pub(super) tokens: u32,
+        // When the last token was added, or taken from a full bucket.
+        pub(super) refilled_at: Duration,
+        // The item taken from the stream while waiting for a token.
+        pub(super) next: Option<S::Item>,
+    }
+}
+
+impl<S, T> Stream for RateLimit<S, T>
+where
+    S: Stream,
+    T: Timer,
+{
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let mut this = self.project();
+
+        let now = this.timer.now();
+        if *this.tokens < *this.capacity {
+            let elapsed = now.saturating_sub(*this.refilled_at);
+            let added = elapsed.as_nanos() / this.refill.as_nanos();
+            if added >= u128::from(*this.capacity - *this.tokens) {
+                *this.tokens = *this.capacity;
+            } else {
+                // Less than `capacity` tokens were added, so this fits into a `u32`.
+                *this.tokens += added as u32;
+                *this.refilled_at += *this.refill * added as u32;
+            }
+        }
+        if *this.tokens == *this.capacity {
+            *this.refilled_at = now;
+        }
+        if *this.tokens == 0 {
+            // Look one item ahead, so that the end of the stream is not held back by the wait.
+            if this.next.is_none() {
+                match ready!(this.stream.as_mut().poll_next(cx)) {
+                    Step::Ready(item) => *this.next = Some(item),
+                    step => return Poll::Ready(step),
+                }
+            }
+            this.timer.wake_at(*this.refilled_at + *this.refill, cx);
+            return Poll::Pending;
+        }
+
+        let step = match this.next.take() {
+            Some(item) => Step::Ready(item),
+            None => ready!(this.stream.poll_next(cx)),
+        };
+        if step.is_ready() {
+            *this.tokens -= 1;
+        }
+        Poll::Ready(step)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+        let next = usize::from(self.next.is_some());
+        (
+            lower.saturating_add(next),
+            upper.and_then(|upper| upper.checked_add(next)),
+        )
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    extern crate std;
+
+    use core::{
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+    use std::vec::Vec;
+
+    use crate::{
+        step::Step,
+        stream::{ext::StreamExt, IntoFusion, Stream},
+    };
+
+    #[test]
+    fn rate_limit() {
+        use core::{pin::Pin, time::Duration};
+
+        use crate::time::ManualTimer;
+
+        /// Polls `stream` until it stops yielding items.
+        fn burst<S: Stream>(mut stream: Pin<&mut S>, cx: &mut Context<'_>) -> Vec<S::Item> {
+            let mut items = Vec::new();
+            while let Poll::Ready(Step::Ready(item)) = stream.as_mut().poll_next(cx) {
+                items.push(item);
+            }
+            items
+        }
+
+        let ms = Duration::from_millis;
+        let timer = ManualTimer::new();
+        let mut cx = Context::from_waker(Waker::noop());
+
+        let mut throttled = pin!((0..3).into_fusion().throttle(ms(10), &timer));
+        assert_eq!(burst(throttled.as_mut(), &mut cx), [0]);
+        assert_eq!(timer.waiting(), 1);
+        timer.advance(ms(5));
+        assert!(throttled.as_mut().poll_next(&mut cx).is_pending());
+        timer.advance(ms(5));
+        assert_eq!(timer.waiting(), 0);
+        assert_eq!(burst(throttled.as_mut(), &mut cx), [1]);
+        // Waiting longer does not let more items through at once.
+        timer.advance(ms(100));
+        assert_eq!(burst(throttled.as_mut(), &mut cx), [2]);
+        // The end of the stream is not held back until the next item could pass.
+        assert_eq!(
+            throttled.as_mut().poll_next(&mut cx),
+            Poll::Ready(Step::Done)
+        );
+        assert_eq!(timer.waiting(), 0);
+
+        let mut limited = pin!((0..10).into_fusion().rate_limit(3, ms(10), &timer));
+        assert_eq!(burst(limited.as_mut(), &mut cx), [0, 1, 2]);
+        timer.advance(ms(10));
+        assert_eq!(burst(limited.as_mut(), &mut cx), [3]);
+        // Time left over from a refill counts towards the next one.
+        timer.advance(ms(25));
+        assert_eq!(burst(limited.as_mut(), &mut cx), [4, 5]);
+        timer.advance(ms(5));
+        assert_eq!(burst(limited.as_mut(), &mut cx), [6]);
+        timer.advance(ms(1000));
+        assert_eq!(burst(limited.as_mut(), &mut cx), [7, 8, 9]);
+        assert_eq!(limited.as_mut().poll_next(&mut cx), Poll::Ready(Step::Done));
+        assert_eq!(timer.waiting(), 0);
+
+        // An item taken from the stream while waiting still waits for its token.
+        let mut limited = pin!((0..3).into_fusion().rate_limit(2, ms(10), &timer));
+        assert_eq!(burst(limited.as_mut(), &mut cx), [0, 1]);
+        assert_eq!(limited.size_hint(), (1, Some(1)));
+        timer.advance(ms(9));
+        assert!(limited.as_mut().poll_next(&mut cx).is_pending());
+        timer.advance(ms(1));
+        assert_eq!(burst(limited.as_mut(), &mut cx), [2]);
+        assert_eq!(limited.as_mut().poll_next(&mut cx), Poll::Ready(Step::Done));
+    }
+}
@@ -0,0 +1,39 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use pin_project_lite::pin_project;
+
+use super::{DoubleEndedStream, ExactSizeStream, Stream};
+use crate::step::Step;
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Rev<S> {
+        #[pin]
+        pub(super) stream: S,
+    }
+}
+
+impl<S: DoubleEndedStream> Stream for Rev<S> {
+    type Item = S::Item;
+
+    const SYNC: bool = S::SYNC;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        self.project().stream.poll_next_back(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+impl<S: DoubleEndedStream + ExactSizeStream> ExactSizeStream for Rev<S> {}
+
+impl<S: DoubleEndedStream> DoubleEndedStream for Rev<S> {
+    fn poll_next_back(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        self.project().stream.poll_next(cx)
+    }
+}
@@ -0,0 +1,85 @@
+use core::{
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::ready;
+
+use super::Stream;
+use crate::step::Step;
+
+/// Alternates strictly between `streams`, taking one item from each in turn.
+///
+/// A stream returning [`Step::NotYet`] keeps its turn. Streams that are done drop out of the
+/// rotation, and the round robin is done once all of them are. `streams` can be any container of
+/// [`Unpin`] streams, such as an array or a `Vec`.
+pub fn round_robin<C, S>(streams: C) -> RoundRobin<C, S>
+where
+    C: AsMut<[S]> + AsRef<[S]>,
+    S: Stream + Unpin,
+{
+    let live = streams.as_ref().len();
+    RoundRobin {
+        streams,
+        live,
+        turn: 0,
+        _marker: PhantomData,
+    }
+}
+
+#[must_use = "streams do nothing unless polled"]
+pub struct RoundRobin<C, S> {
+    streams: C,
+    live: usize,
+    turn: usize,
+    _marker: PhantomData<fn() -> S>,
+}
+
+impl<C, S> Unpin for RoundRobin<C, S> {}
+
+impl<C, S> Stream for RoundRobin<C, S>
+where
+    C: AsMut<[S]> + AsRef<[S]>,
+    S: Stream + Unpin,
+{
+    type Item = S::Item;
+
+    const SYNC: bool = S::SYNC;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let this = self.get_mut();
+
+        while this.live > 0 {
+            let streams = &mut this.streams.as_mut()[..this.live];
+            match ready!(Pin::new(&mut streams[this.turn]).poll_next(cx)) {
+                Step::Ready(item) => {
+                    this.turn = (this.turn + 1) % this.live;
+                    return Poll::Ready(Step::Ready(item));
+                }
+                Step::NotYet => return Poll::Ready(Step::NotYet),
+                Step::Done => {
+                    // Move the finished stream behind the live ones, keeping the rotation order.
+                    streams[this.turn..].rotate_left(1);
+                    this.live -= 1;
+                    if this.turn == this.live {
+                        this.turn = 0;
+                    }
+                }
+            }
+        }
+        Poll::Ready(Step::Done)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.streams.as_ref()[..self.live]
+            .iter()
+            .map(Stream::size_hint)
+            .fold((0, Some(0)), |(lower, upper), (l, u)| {
+                (
+                    lower.saturating_add(l),
+                    upper.zip(u).and_then(|(upper, u)| upper.checked_add(u)),
+                )
+            })
+    }
+}
@@ -0,0 +1,80 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::ready;
+use pin_project_lite::pin_project;
+
+use super::Stream;
+use crate::{rng::Rng, step::Step};
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct SampleBernoulli<S, R> {
+        #[pin]
+        pub(super) stream: S,
+        pub(super) p: f64,
+        pub(super) rng: R,
+    }
+}
+
+impl<S, R> Stream for SampleBernoulli<S, R>
+where
+    S: Stream,
+    R: Rng,
+{
+    type Item = S::Item;
+
+    const SYNC: bool = S::SYNC;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let this = self.project();
+        Poll::Ready(ready!(this.stream.poll_next(cx)).filter(|_| this.rng.gen_f64() < *this.p))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+        if self.p >= 1.0 {
+            (lower, upper)
+        } else {
+            (0, upper)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use core::task::Poll;
+
+    use crate::{
+        step::Step,
+        stream::{ext::StreamExt, tests::drain, Stream},
+    };
+
+    #[test]
+    fn sample_bernoulli() {
+        use crate::{rng::SplitMix64, stream::source};
+
+        // A stream of unknown length.
+        let counter = |n| {
+            let mut i = 0;
+            source::from_fn(move |_| {
+                i += 1;
+                Poll::Ready(if i <= n { Step::Ready(i) } else { Step::Done })
+            })
+        };
+        let sampled = |p| {
+            let sample = counter(10_000).sample_bernoulli(p, SplitMix64::new(1));
+            assert_eq!(sample.size_hint(), (0, None));
+            drain(sample)
+        };
+        assert!((2800..=3200).contains(&sampled(0.3).len()));
+        assert_eq!(sampled(0.0), []);
+        assert_eq!(sampled(1.0).len(), 10_000);
+        // The same seed picks the same items.
+        assert_eq!(sampled(0.5), sampled(0.5));
+    }
+}
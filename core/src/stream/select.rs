@@ -0,0 +1,177 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use pin_project_lite::pin_project;
+
+use super::{fuse::Fuse, Stream};
+use crate::step::Step;
+
+/// Decides which side of a [`Select`] is polled first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// Alternates sides after every item.
+    RoundRobin,
+    /// Always polls the left side first.
+    Biased,
+    /// Polls the left side first for `left` items, then the right side first for `right` items.
+    Weighted { left: usize, right: usize },
+}
+
+/// Interleaves the items of `left` and `right` as they become ready, alternating between them.
+pub fn merge<S, U>(left: S, right: U) -> Select<S, U>
+where
+    S: Stream,
+    U: Stream<Item = S::Item>,
+{
+    select_with_strategy(left, right, Strategy::RoundRobin)
+}
+
+/// Interleaves the items of `left` and `right` as they become ready, preferring sides according to
+/// `strategy`.
+///
+/// A side that returns [`Step::NotYet`], is pending or is done lets the other side proceed within
+/// the same poll.
+pub fn select_with_strategy<S, U>(left: S, right: U, strategy: Strategy) -> Select<S, U>
+where
+    S: Stream,
+    U: Stream<Item = S::Item>,
+{
+    if let Strategy::Weighted { left, right } = strategy {
+        assert!(left > 0 && right > 0, "weights must be greater than zero");
+    }
+    Select {
+        left: Fuse {
+            stream: left,
+            done: false,
+        },
+        right: Fuse {
+            stream: right,
+            done: false,
+        },
+        strategy,
+        right_first: false,
+        credit: 0,
+    }
+}
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Select<S, U> {
+        #[pin]
+        left: Fuse<S>,
+        #[pin]
+        right: Fuse<U>,
+        strategy: Strategy,
+        right_first: bool,
+        credit: usize,
+    }
+}
+
+impl<S: Stream, U: Stream<Item = S::Item>> Stream for Select<S, U> {
+    type Item = S::Item;
+
+    const SYNC: bool = S::SYNC && U::SYNC;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let mut this = self.project();
+
+        let right_first = *this.right_first;
+        let first = if right_first {
+            this.right.as_mut().poll_next(cx)
+        } else {
+            this.left.as_mut().poll_next(cx)
+        };
+        if let Poll::Ready(Step::Ready(item)) = first {
+            let weight = match *this.strategy {
+                Strategy::RoundRobin => Some(1),
+                Strategy::Biased => None,
+                Strategy::Weighted { left, .. } if !right_first => Some(left),
+                Strategy::Weighted { right, .. } => Some(right),
+            };
+            if let Some(weight) = weight {
+                *this.credit += 1;
+                if *this.credit == weight {
+                    *this.right_first = !right_first;
+                    *this.credit = 0;
+                }
+            }
+            return Poll::Ready(Step::Ready(item));
+        }
+
+        let second = if right_first {
+            this.left.as_mut().poll_next(cx)
+        } else {
+            this.right.as_mut().poll_next(cx)
+        };
+        match (first, second) {
+            (_, Poll::Ready(Step::Ready(item))) => Poll::Ready(Step::Ready(item)),
+            _ if this.left.done && this.right.done => Poll::Ready(Step::Done),
+            (Poll::Ready(Step::NotYet), _) | (_, Poll::Ready(Step::NotYet)) => {
+                Poll::Ready(Step::NotYet)
+            }
+            _ => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (left_lower, left_upper) = self.left.size_hint();
+        let (right_lower, right_upper) = self.right.size_hint();
+        let lower = left_lower.saturating_add(right_lower);
+        let upper = match (left_upper, right_upper) {
+            (Some(left), Some(right)) => left.checked_add(right),
+            _ => None,
+        };
+        (lower, upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::stream::{
+        ext::StreamExt,
+        tests::{assert_size_hints, drain},
+        IntoFusion,
+    };
+
+    #[test]
+    fn select() {
+        use crate::stream::{merge, select_with_strategy, Strategy};
+
+        assert_eq!(
+            drain(merge((0..3).into_fusion(), (10..15).into_fusion())),
+            [0, 10, 1, 11, 2, 12, 13, 14]
+        );
+        // A side rejecting items does not hold back the other one.
+        assert_eq!(
+            drain(merge(
+                (0..6).into_fusion().filter(|i| *i == 5),
+                (10..13).into_fusion()
+            )),
+            [10, 11, 12, 5]
+        );
+        assert_eq!(
+            drain(select_with_strategy(
+                (0..3).into_fusion(),
+                (10..12).into_fusion(),
+                Strategy::Biased
+            )),
+            [0, 1, 2, 10, 11]
+        );
+        assert_eq!(
+            drain(select_with_strategy(
+                (0..6).into_fusion(),
+                (10..13).into_fusion(),
+                Strategy::Weighted { left: 2, right: 1 }
+            )),
+            [0, 1, 10, 2, 3, 11, 4, 5, 12]
+        );
+        assert_size_hints(
+            merge((0..3).into_fusion(), (10..15).into_fusion()),
+            [0, 10, 1, 11, 2, 12, 13, 14].into_iter(),
+        );
+    }
+}
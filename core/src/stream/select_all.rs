@@ -0,0 +1,128 @@
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use super::Stream;
+use crate::step::Step;
+
+/// Interleaves the items of all `streams` as they become ready, polling them round-robin.
+///
+/// A stream that returns [`Step::NotYet`], is pending or is done lets the next one proceed within
+/// the same poll.
+pub fn select_all<I>(streams: I) -> SelectAll<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Stream,
+{
+    SelectAll {
+        streams: streams.into_iter().map(Box::pin).collect(),
+        next: 0,
+    }
+}
+
+#[must_use = "streams do nothing unless polled"]
+pub struct SelectAll<S> {
+    streams: Vec<Pin<Box<S>>>,
+    next: usize,
+}
+
+impl<S> Unpin for SelectAll<S> {}
+
+impl<S> SelectAll<S> {
+    /// Adds `stream` to the set, to be polled after the ones already in it.
+    pub fn push(&mut self, stream: S) {
+        self.streams.push(Box::pin(stream));
+    }
+}
+
+impl<S: Stream> Stream for SelectAll<S> {
+    type Item = S::Item;
+
+    const SYNC: bool = S::SYNC;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let this = self.get_mut();
+        let mut not_yet = false;
+        let mut polled = 0;
+        while polled < this.streams.len() {
+            if this.next >= this.streams.len() {
+                this.next = 0;
+            }
+            match this.streams[this.next].as_mut().poll_next(cx) {
+                Poll::Ready(Step::Ready(item)) => {
+                    this.next += 1;
+                    return Poll::Ready(Step::Ready(item));
+                }
+                Poll::Ready(Step::Done) => {
+                    // The stream after it moves into its slot, so `next` already points at it.
+                    this.streams.remove(this.next);
+                    continue;
+                }
+                Poll::Ready(Step::NotYet) => not_yet = true,
+                Poll::Pending => {}
+            }
+            this.next += 1;
+            polled += 1;
+        }
+        if this.streams.is_empty() {
+            Poll::Ready(Step::Done)
+        } else if not_yet {
+            Poll::Ready(Step::NotYet)
+        } else {
+            Poll::Pending
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.streams.iter().map(|stream| stream.size_hint()).fold(
+            (0, Some(0)),
+            |(lower, upper), (l, u)| {
+                (
+                    lower.saturating_add(l),
+                    upper.zip(u).and_then(|(upper, u)| upper.checked_add(u)),
+                )
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use core::task::Poll;
+    use std::vec::Vec;
+
+    use crate::{
+        step::Step,
+        stream::{tests::drain, Stream},
+    };
+
+    #[test]
+    fn select_all() {
+        use crate::stream::{select_all, source};
+
+        fn scripted(script: Vec<Poll<Step<i32>>>) -> impl Stream<Item = i32> {
+            let mut script = script.into_iter();
+            source::from_fn(move |cx| match script.next() {
+                Some(Poll::Pending) => {
+                    cx.waker().wake_by_ref();
+                    Poll::Pending
+                }
+                Some(next) => next,
+                None => Poll::Ready(Step::Done),
+            })
+        }
+        let ready = |i| Poll::Ready(Step::Ready(i));
+        assert_eq!(
+            drain(select_all([
+                scripted(std::vec![ready(0), ready(1)]),
+                scripted(std::vec![Poll::Ready(Step::NotYet), ready(10), ready(11)]),
+                scripted(std::vec![Poll::Pending, Poll::Pending, ready(20)]),
+            ])),
+            [0, 1, 10, 11, 20]
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use core::{
-    cmp::max,
+    ops::ControlFlow,
     pin::Pin,
     task::{Context, Poll},
 };
@@ -7,7 +7,7 @@ use core::{
 use futures_core::ready;
 use pin_project_lite::pin_project;
 
-use super::Stream;
+use super::{coalesce, DoubleEndedStream, ExactSizeStream, Stream};
 use crate::step::Step;
 
 pin_project! {
@@ -16,32 +16,80 @@ pin_project! {
         #[pin]
         pub(super) stream: S,
         pub(super) n: usize,
+        pub(super) coalesce: usize,
+    }
+}
+
+impl<S> Skip<S> {
+    /// Steps over up to `n` skipped items in a single poll, like [`Filter::coalesce()`] does for
+    /// rejected ones.
+    ///
+    /// [`Filter::coalesce()`]: super::filter::Filter::coalesce()
+    pub fn coalesce(mut self, n: usize) -> Self {
+        assert!(n > 0, "`n` must be greater than zero");
+        self.coalesce = n;
+        self
     }
 }
 
 impl<S: Stream> Stream for Skip<S> {
     type Item = S::Item;
 
+    const SYNC: bool = S::SYNC;
+
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
         let mut this = self.project();
-        Poll::Ready(match ready!(this.stream.as_mut().poll_next(cx)) {
-            Step::NotYet => Step::NotYet,
-            Step::Ready(item) => match *this.n {
-                0 => return Poll::Ready(Step::Ready(item)),
-                _ => {
-                    *this.n -= 1;
-                    Step::NotYet
-                }
-            },
-            Step::Done => Step::Done,
-        })
+        for _ in 0..*this.coalesce {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Step::Ready(_) if *this.n > 0 => *this.n -= 1,
+                next => return Poll::Ready(next),
+            }
+        }
+        Poll::Ready(Step::NotYet)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
         let size = self.stream.size_hint();
         (
-            max(0, size.0 - self.n),
-            size.1.map(|size| max(0, size - self.n)),
+            size.0.saturating_sub(self.n),
+            size.1.map(|size| size.saturating_sub(self.n)),
         )
     }
+
+    #[inline]
+    fn poll_fold_n<B, F>(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        budget: &mut usize,
+        acc: &mut B,
+        mut f: F,
+    ) -> Poll<Step<()>>
+    where
+        F: FnMut(&mut B, Self::Item) -> ControlFlow<()>,
+    {
+        let this = self.project();
+        let n = this.n;
+        coalesce::poll_fold_n(this.stream, cx, budget, *this.coalesce, acc, |acc, item| {
+            if *n == 0 {
+                Some(f(acc, item))
+            } else {
+                *n -= 1;
+                None
+            }
+        })
+    }
+}
+
+impl<S: ExactSizeStream> ExactSizeStream for Skip<S> {}
+
+impl<S: DoubleEndedStream + ExactSizeStream> DoubleEndedStream for Skip<S> {
+    fn poll_next_back(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<S::Item>> {
+        let this = self.project();
+
+        if this.stream.len() > *this.n {
+            this.stream.poll_next_back(cx)
+        } else {
+            Poll::Ready(Step::Done)
+        }
+    }
 }
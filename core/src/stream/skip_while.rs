@@ -1,4 +1,5 @@
 use core::{
+    ops::ControlFlow,
     pin::Pin,
     task::{Context, Poll},
 };
@@ -6,7 +7,7 @@ use core::{
 use futures_core::ready;
 use pin_project_lite::pin_project;
 
-use super::Stream;
+use super::{coalesce, Stream};
 use crate::step::Step;
 
 pin_project! {
@@ -15,6 +16,19 @@ pin_project! {
         #[pin]
         pub(super) stream: S,
         pub(super) predicate: Option<P>,
+        pub(super) coalesce: usize,
+    }
+}
+
+impl<S, P> SkipWhile<S, P> {
+    /// Steps over up to `n` skipped items in a single poll, like [`Filter::coalesce()`] does for
+    /// rejected ones.
+    ///
+    /// [`Filter::coalesce()`]: super::filter::Filter::coalesce()
+    pub fn coalesce(mut self, n: usize) -> Self {
+        assert!(n > 0, "`n` must be greater than zero");
+        self.coalesce = n;
+        self
     }
 }
 
@@ -25,26 +39,52 @@ where
 {
     type Item = S::Item;
 
+    const SYNC: bool = S::SYNC;
+
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
         let mut this = self.project();
-        Poll::Ready(match ready!(this.stream.as_mut().poll_next(cx)) {
-            Step::NotYet => Step::NotYet,
-            Step::Ready(v) => match this.predicate {
-                Some(p) => {
-                    if !p(&v) {
+        for _ in 0..*this.coalesce {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Step::Ready(v) if this.predicate.as_mut().is_some_and(|p| p(&v)) => {}
+                next => {
+                    if let Step::Ready(_) = next {
                         *this.predicate = None;
-                        Step::Ready(v)
-                    } else {
-                        Step::NotYet
                     }
+                    return Poll::Ready(next);
                 }
-                None => Step::Ready(v),
-            },
-            Step::Done => Step::Done,
-        })
+            }
+        }
+        Poll::Ready(Step::NotYet)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.stream.size_hint()
+        match self.predicate {
+            Some(_) => (0, self.stream.size_hint().1),
+            None => self.stream.size_hint(),
+        }
+    }
+
+    #[inline]
+    fn poll_fold_n<B, F>(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        budget: &mut usize,
+        acc: &mut B,
+        mut f: F,
+    ) -> Poll<Step<()>>
+    where
+        F: FnMut(&mut B, Self::Item) -> ControlFlow<()>,
+    {
+        let this = self.project();
+        let predicate = this.predicate;
+        coalesce::poll_fold_n(this.stream, cx, budget, *this.coalesce, acc, |acc, item| {
+            if let Some(p) = predicate {
+                if p(&item) {
+                    return None;
+                }
+                *predicate = None;
+            }
+            Some(f(acc, item))
+        })
     }
 }
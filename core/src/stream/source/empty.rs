@@ -0,0 +1,46 @@
+use core::{
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::{
+    step::Step,
+    stream::{DoubleEndedStream, ExactSizeStream, Stream},
+};
+
+/// Creates a stream that is done right away.
+pub fn empty<T>() -> Empty<T> {
+    Empty {
+        _marker: PhantomData,
+    }
+}
+
+#[must_use = "streams do nothing unless polled"]
+pub struct Empty<T> {
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Unpin for Empty<T> {}
+
+impl<T> Stream for Empty<T> {
+    type Item = T;
+
+    const SYNC: bool = true;
+
+    fn poll_next(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        Poll::Ready(Step::Done)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(0))
+    }
+}
+
+impl<T> DoubleEndedStream for Empty<T> {
+    fn poll_next_back(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        Poll::Ready(Step::Done)
+    }
+}
+
+impl<T> ExactSizeStream for Empty<T> {}
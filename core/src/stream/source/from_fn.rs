@@ -0,0 +1,32 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::{step::Step, stream::Stream};
+
+/// Creates a stream whose every poll is handed to `f`.
+pub fn from_fn<T, F>(f: F) -> FromFn<F>
+where
+    F: FnMut(&mut Context<'_>) -> Poll<Step<T>>,
+{
+    FromFn { f }
+}
+
+#[must_use = "streams do nothing unless polled"]
+pub struct FromFn<F> {
+    f: F,
+}
+
+impl<F> Unpin for FromFn<F> {}
+
+impl<T, F> Stream for FromFn<F>
+where
+    F: FnMut(&mut Context<'_>) -> Poll<Step<T>>,
+{
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        (self.get_mut().f)(cx)
+    }
+}
@@ -0,0 +1,97 @@
+//! Constructors for streams that do not start from an existing iterator.
+
+pub mod empty;
+pub mod from_fn;
+pub mod once;
+pub mod pending;
+pub mod repeat;
+pub mod repeat_with;
+pub mod successors;
+pub mod unfold;
+
+pub use empty::empty;
+pub use from_fn::from_fn;
+pub use once::once;
+pub use pending::pending;
+pub use repeat::repeat;
+pub use repeat_with::repeat_with;
+pub use successors::successors;
+pub use unfold::unfold;
+
+use super::{IntoFusion, IteratorStream};
+
+/// Turns `iter` into a stream, like [`IntoFusion::into_fusion()`].
+pub fn iter<I: IntoIterator>(iter: I) -> IteratorStream<I::IntoIter> {
+    iter.into_iter().into_fusion()
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use core::{
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+    use std::vec::Vec;
+
+    use crate::{
+        consumer::ConsumerExt,
+        step::Step,
+        stream::{ext::StreamExt, tests::assert_size_hints, Stream},
+    };
+
+    #[test]
+    fn sources() {
+        use crate::stream::source;
+
+        assert_size_hints(source::once(7), core::iter::once(7));
+        assert_size_hints(source::empty::<i32>(), core::iter::empty());
+        assert_size_hints(source::iter([1, 2, 3]), [1, 2, 3].into_iter());
+        assert_size_hints(
+            source::successors(Some(1u32), |i| i.checked_mul(10)),
+            core::iter::successors(Some(1u32), |i| i.checked_mul(10)),
+        );
+        assert_size_hints(source::repeat(4).take(5), core::iter::repeat_n(4, 5));
+        let mut i = 0;
+        assert_size_hints(
+            source::repeat_with(|| {
+                i += 1;
+                i
+            })
+            .take(3),
+            1..=3,
+        );
+
+        let mut n = 0;
+        let from_fn: Vec<_> = spin_on::spin_on(
+            source::from_fn(|cx| {
+                n += 1;
+                match n {
+                    1 | 3 => {
+                        cx.waker().wake_by_ref();
+                        Poll::Pending
+                    }
+                    2 | 4 => Poll::Ready(Step::NotYet),
+                    5..=7 => Poll::Ready(Step::Ready(n)),
+                    _ => Poll::Ready(Step::Done),
+                }
+            })
+            .collect()
+            .yield_by(2),
+        );
+        assert_eq!(from_fn, [5, 6, 7]);
+
+        let unfold: Vec<_> = spin_on::spin_on(
+            source::unfold(0, |i| async move { (i < 4).then_some((i * i, i + 1)) })
+                .collect()
+                .yield_by(2),
+        );
+        assert_eq!(unfold, [0, 1, 4, 9]);
+
+        let mut cx = Context::from_waker(Waker::noop());
+        let mut pending = pin!(source::pending::<i32>());
+        assert!(pending.as_mut().poll_next(&mut cx).is_pending());
+        assert_eq!(pending.size_hint(), (0, Some(0)));
+    }
+}
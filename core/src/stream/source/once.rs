@@ -0,0 +1,44 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::{
+    step::Step,
+    stream::{DoubleEndedStream, ExactSizeStream, Stream},
+};
+
+/// Creates a stream that yields `value` once.
+pub fn once<T>(value: T) -> Once<T> {
+    Once { value: Some(value) }
+}
+
+#[must_use = "streams do nothing unless polled"]
+pub struct Once<T> {
+    value: Option<T>,
+}
+
+impl<T> Unpin for Once<T> {}
+
+impl<T> Stream for Once<T> {
+    type Item = T;
+
+    const SYNC: bool = true;
+
+    fn poll_next(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        Poll::Ready(self.get_mut().value.take().into())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.value.is_some() as usize;
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedStream for Once<T> {
+    fn poll_next_back(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        self.poll_next(cx)
+    }
+}
+
+impl<T> ExactSizeStream for Once<T> {}
@@ -0,0 +1,33 @@
+use core::{
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::{step::Step, stream::Stream};
+
+/// Creates a stream that never yields and never completes.
+pub fn pending<T>() -> Pending<T> {
+    Pending {
+        _marker: PhantomData,
+    }
+}
+
+#[must_use = "streams do nothing unless polled"]
+pub struct Pending<T> {
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Unpin for Pending<T> {}
+
+impl<T> Stream for Pending<T> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        Poll::Pending
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(0))
+    }
+}
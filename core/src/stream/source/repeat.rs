@@ -0,0 +1,41 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::{
+    step::Step,
+    stream::{DoubleEndedStream, Stream},
+};
+
+/// Creates a stream that yields clones of `value` forever.
+pub fn repeat<T: Clone>(value: T) -> Repeat<T> {
+    Repeat { value }
+}
+
+#[must_use = "streams do nothing unless polled"]
+pub struct Repeat<T> {
+    value: T,
+}
+
+impl<T> Unpin for Repeat<T> {}
+
+impl<T: Clone> Stream for Repeat<T> {
+    type Item = T;
+
+    const SYNC: bool = true;
+
+    fn poll_next(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        Poll::Ready(Step::Ready(self.value.clone()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<T: Clone> DoubleEndedStream for Repeat<T> {
+    fn poll_next_back(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        self.poll_next(cx)
+    }
+}
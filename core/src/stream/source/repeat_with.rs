@@ -0,0 +1,41 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::{
+    step::Step,
+    stream::{DoubleEndedStream, Stream},
+};
+
+/// Creates a stream that yields the results of calling `f` forever.
+pub fn repeat_with<T, F: FnMut() -> T>(f: F) -> RepeatWith<F> {
+    RepeatWith { f }
+}
+
+#[must_use = "streams do nothing unless polled"]
+pub struct RepeatWith<F> {
+    f: F,
+}
+
+impl<F> Unpin for RepeatWith<F> {}
+
+impl<T, F: FnMut() -> T> Stream for RepeatWith<F> {
+    type Item = T;
+
+    const SYNC: bool = true;
+
+    fn poll_next(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        Poll::Ready(Step::Ready((self.get_mut().f)()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<T, F: FnMut() -> T> DoubleEndedStream for RepeatWith<F> {
+    fn poll_next_back(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        self.poll_next(cx)
+    }
+}
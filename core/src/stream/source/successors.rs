@@ -0,0 +1,46 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::{step::Step, stream::Stream};
+
+/// Creates a stream that starts at `first` and computes every following item from the previous
+/// one, until `f` returns `None`.
+pub fn successors<T, F>(first: Option<T>, f: F) -> Successors<T, F>
+where
+    F: FnMut(&T) -> Option<T>,
+{
+    Successors { next: first, f }
+}
+
+#[must_use = "streams do nothing unless polled"]
+pub struct Successors<T, F> {
+    next: Option<T>,
+    f: F,
+}
+
+impl<T, F> Unpin for Successors<T, F> {}
+
+impl<T, F> Stream for Successors<T, F>
+where
+    F: FnMut(&T) -> Option<T>,
+{
+    type Item = T;
+
+    const SYNC: bool = true;
+
+    fn poll_next(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let this = self.get_mut();
+        let item = this.next.take();
+        this.next = item.as_ref().and_then(&mut this.f);
+        Poll::Ready(item.into())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.next {
+            Some(_) => (1, None),
+            None => (0, Some(0)),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::ready;
+use pin_project_lite::pin_project;
+
+use crate::{step::Step, stream::Stream};
+
+/// Creates a stream from a seed and an async closure that turns the seed into the next item and
+/// the next seed, until it resolves to `None`.
+pub fn unfold<T, F, Fut, Item>(init: T, f: F) -> Unfold<T, F, Fut>
+where
+    F: FnMut(T) -> Fut,
+    Fut: Future<Output = Option<(Item, T)>>,
+{
+    Unfold {
+        state: Some(init),
+        f,
+        future: None,
+    }
+}
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct Unfold<T, F, Fut> {
+        state: Option<T>,
+        f: F,
+        #[pin]
+        future: Option<Fut>,
+    }
+}
+
+impl<T, F, Fut, Item> Stream for Unfold<T, F, Fut>
+where
+    F: FnMut(T) -> Fut,
+    Fut: Future<Output = Option<(Item, T)>>,
+{
+    type Item = Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let mut this = self.project();
+        if let Some(state) = this.state.take() {
+            this.future.set(Some((this.f)(state)));
+        }
+        let Some(future) = this.future.as_mut().as_pin_mut() else {
+            return Poll::Ready(Step::Done);
+        };
+        let next = ready!(future.poll(cx));
+        this.future.set(None);
+        Poll::Ready(match next {
+            Some((item, state)) => {
+                *this.state = Some(state);
+                Step::Ready(item)
+            }
+            None => Step::Done,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.state.is_none() && self.future.is_none() {
+            (0, Some(0))
+        } else {
+            (0, None)
+        }
+    }
+}
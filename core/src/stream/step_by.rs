@@ -1,4 +1,5 @@
 use core::{
+    ops::ControlFlow,
     pin::Pin,
     task::{Context, Poll},
 };
@@ -6,7 +7,7 @@ use core::{
 use futures_core::ready;
 use pin_project_lite::pin_project;
 
-use super::Stream;
+use super::{coalesce, ExactSizeStream, Stream};
 use crate::step::Step;
 
 pin_project! {
@@ -16,26 +17,72 @@ pin_project! {
         pub(super) stream: S,
         pub(super) step: usize,
         pub(super) i: usize,
+        pub(super) coalesce: usize,
+    }
+}
+
+impl<S> StepBy<S> {
+    /// Steps over up to `n` items between two steps in a single poll, like
+    /// [`Filter::coalesce()`] does for rejected ones.
+    ///
+    /// [`Filter::coalesce()`]: super::filter::Filter::coalesce()
+    pub fn coalesce(mut self, n: usize) -> Self {
+        assert!(n > 0, "`n` must be greater than zero");
+        self.coalesce = n;
+        self
     }
 }
 
 impl<S: Stream> Stream for StepBy<S> {
     type Item = S::Item;
 
+    const SYNC: bool = S::SYNC;
+
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
         let mut this = self.project();
-        Poll::Ready(match ready!(this.stream.as_mut().poll_next(cx)) {
-            Step::NotYet => Step::NotYet,
-            Step::Ready(v) => {
-                if *this.i == 0 {
-                    *this.i = *this.step - 1;
-                    Step::Ready(v)
-                } else {
-                    *this.i -= 1;
-                    Step::NotYet
+        for _ in 0..*this.coalesce {
+            match ready!(this.stream.as_mut().poll_next(cx)) {
+                Step::Ready(_) if *this.i > 0 => *this.i -= 1,
+                next => {
+                    if let Step::Ready(_) = next {
+                        *this.i = *this.step - 1;
+                    }
+                    return Poll::Ready(next);
                 }
             }
-            Step::Done => Step::Done,
+        }
+        Poll::Ready(Step::NotYet)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+        let f = |n: usize| n.saturating_sub(self.i).div_ceil(self.step);
+        (f(lower), upper.map(f))
+    }
+
+    #[inline]
+    fn poll_fold_n<B, F>(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        budget: &mut usize,
+        acc: &mut B,
+        mut f: F,
+    ) -> Poll<Step<()>>
+    where
+        F: FnMut(&mut B, Self::Item) -> ControlFlow<()>,
+    {
+        let this = self.project();
+        let (step, i) = (*this.step, this.i);
+        coalesce::poll_fold_n(this.stream, cx, budget, *this.coalesce, acc, |acc, item| {
+            if *i == 0 {
+                *i = step - 1;
+                Some(f(acc, item))
+            } else {
+                *i -= 1;
+                None
+            }
         })
     }
 }
+
+impl<S: ExactSizeStream> ExactSizeStream for StepBy<S> {}
@@ -0,0 +1,191 @@
+use core::{
+    ops::ControlFlow,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use super::Stream;
+use crate::step::Step;
+
+/// Drives a [`Stream::SYNC`] stream as a plain [`Iterator`] for at most `budget` steps.
+///
+/// Once the iterator stops, `end` holds what [`poll_fold_n()`][`Stream::poll_fold_n()`] would
+/// have returned. [`fold()`][`Iterator::fold()`] and everything built on it (`count()`,
+/// `for_each()`, ...) run as a single `poll_fold_n()` call rather than one `poll_next()` per item,
+/// so the iterator should be consumed by value, not through `by_ref()`.
+pub(crate) struct SyncIter<'a, 'cx, S: ?Sized> {
+    stream: Pin<&'a mut S>,
+    cx: &'a mut Context<'cx>,
+    budget: &'a mut usize,
+    end: &'a mut Poll<Step<()>>,
+}
+
+impl<'a, 'cx, S: Stream + ?Sized> SyncIter<'a, 'cx, S> {
+    /// `end` must start out as `Poll::Ready(Step::NotYet)`; it stays that way if the budget runs
+    /// out and becomes [`Step::Done`] once the stream is exhausted.
+    pub(crate) fn new(
+        stream: Pin<&'a mut S>,
+        cx: &'a mut Context<'cx>,
+        budget: &'a mut usize,
+        end: &'a mut Poll<Step<()>>,
+    ) -> Self {
+        debug_assert!(S::SYNC);
+        Self {
+            stream,
+            cx,
+            budget,
+            end,
+        }
+    }
+
+    fn is_running(&self) -> bool {
+        *self.budget > 0 && matches!(self.end, Poll::Ready(Step::NotYet))
+    }
+}
+
+impl<S: Stream + ?Sized> Iterator for SyncIter<'_, '_, S> {
+    type Item = S::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.is_running() {
+            match self.stream.as_mut().poll_next(self.cx) {
+                Poll::Ready(step) => {
+                    *self.budget -= 1;
+                    match step {
+                        Step::NotYet => {}
+                        Step::Ready(item) => return Some(item),
+                        Step::Done => *self.end = Poll::Ready(Step::Done),
+                    }
+                }
+                // Not expected from a synchronous stream, but its waker is registered all the same.
+                Poll::Pending => *self.end = Poll::Pending,
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let upper = self.stream.size_hint().1;
+        (
+            0,
+            Some(upper.map_or(*self.budget, |upper| upper.min(*self.budget))),
+        )
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        if !self.is_running() {
+            return init;
+        }
+        let mut acc = Some(init);
+        *self.end = self
+            .stream
+            .poll_fold_n(self.cx, self.budget, &mut acc, |acc, item| {
+                *acc = acc.take().map(|acc| f(acc, item));
+                ControlFlow::Continue(())
+            });
+        acc.unwrap()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        let mut count = 0;
+        if self.is_running() {
+            *self.end = self
+                .stream
+                .poll_fold_n(self.cx, self.budget, &mut count, |count, _| {
+                    *count += 1;
+                    ControlFlow::Continue(())
+                });
+        }
+        count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use core::task::{Context, Poll};
+    use std::vec::Vec;
+
+    use crate::{
+        consumer::ConsumerExt,
+        step::Step,
+        stream::{ext::StreamExt, IntoFusion, Stream},
+    };
+
+    #[test]
+    fn sync_consumers() {
+        for step in [1, 2, 7, 4096] {
+            let count = spin_on::spin_on(
+                (0..2048)
+                    .into_fusion()
+                    .skip(5)
+                    .filter(|i| i % 3 == 0)
+                    .take(500)
+                    .count()
+                    .yield_by(step),
+            );
+            assert_eq!(
+                count,
+                (0..2048).skip(5).filter(|i| i % 3 == 0).take(500).count()
+            );
+
+            let collected: Vec<_> = spin_on::spin_on(
+                (0..2048)
+                    .into_fusion()
+                    .step_by(3)
+                    .map(|i| i * 7)
+                    .collect()
+                    .yield_by(step),
+            );
+            assert_eq!(
+                collected,
+                (0..2048).step_by(3).map(|i| i * 7).collect::<Vec<_>>()
+            );
+
+            let folded = spin_on::spin_on(
+                (0..2048)
+                    .into_fusion()
+                    .filter(|i| i % 5 != 0)
+                    .fold(0, |acc, i| acc ^ (i * 3))
+                    .yield_by(step),
+            );
+            assert_eq!(
+                folded,
+                (0..2048)
+                    .filter(|i| i % 5 != 0)
+                    .fold(0, |acc, i| acc ^ (i * 3))
+            );
+        }
+
+        // A synchronous stream that is pending anyway is waited on rather than trusted.
+        struct Hiccup(core::ops::Range<i32>, bool);
+        impl Stream for Hiccup {
+            type Item = i32;
+
+            const SYNC: bool = true;
+
+            fn poll_next(
+                mut self: core::pin::Pin<&mut Self>,
+                cx: &mut Context<'_>,
+            ) -> Poll<Step<i32>> {
+                self.1 = !self.1;
+                if self.1 {
+                    cx.waker().wake_by_ref();
+                    return Poll::Pending;
+                }
+                Poll::Ready(self.0.next().map_or(Step::Done, Step::Ready))
+            }
+        }
+        let collected: Vec<_> = spin_on::spin_on(Hiccup(0..10, false).collect().yield_by(4));
+        assert_eq!(collected, (0..10).collect::<Vec<_>>());
+        let count = spin_on::spin_on(Hiccup(0..10, false).count().yield_by(4));
+        assert_eq!(count, 10);
+    }
+}
@@ -1,5 +1,6 @@
 use core::{
     cmp::min,
+    ops::ControlFlow,
     pin::Pin,
     task::{Context, Poll},
 };
@@ -7,7 +8,7 @@ use core::{
 use futures_core::ready;
 use pin_project_lite::pin_project;
 
-use super::Stream;
+use super::{DoubleEndedStream, ExactSizeStream, Stream};
 use crate::step::Step;
 
 pin_project! {
@@ -22,6 +23,8 @@ pin_project! {
 impl<S: Stream> Stream for Take<S> {
     type Item = S::Item;
 
+    const SYNC: bool = S::SYNC;
+
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<S::Item>> {
         let this = self.project();
 
@@ -46,6 +49,64 @@ impl<S: Stream> Stream for Take<S> {
             Some(max) => min(max, self.n),
             None => self.n,
         };
-        (size.0, Some(max))
+        (min(size.0, self.n), Some(max))
+    }
+
+    #[inline]
+    fn poll_fold_n<B, F>(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        budget: &mut usize,
+        acc: &mut B,
+        mut f: F,
+    ) -> Poll<Step<()>>
+    where
+        F: FnMut(&mut B, Self::Item) -> ControlFlow<()>,
+    {
+        let this = self.project();
+        if *this.n == 0 {
+            return Poll::Ready(Step::Done);
+        }
+        let n = this.n;
+        let mut stopped = false;
+        let next = ready!(this.stream.poll_fold_n(cx, budget, acc, |acc, item| {
+            *n -= 1;
+            let flow = f(acc, item);
+            stopped = flow.is_break();
+            if *n == 0 {
+                ControlFlow::Break(())
+            } else {
+                flow
+            }
+        }));
+        Poll::Ready(match next {
+            // The `n`th item ended the fold, not `f`.
+            Step::Ready(()) if !stopped => Step::Done,
+            next => next,
+        })
+    }
+}
+
+impl<S: ExactSizeStream> ExactSizeStream for Take<S> {}
+
+impl<S: DoubleEndedStream + ExactSizeStream> DoubleEndedStream for Take<S> {
+    fn poll_next_back(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<S::Item>> {
+        let mut this = self.project();
+
+        if *this.n == 0 {
+            return Poll::Ready(Step::Done);
+        }
+        // Items past the first `n` are never yielded, drop them first.
+        loop {
+            let len = this.stream.len();
+            match ready!(this.stream.as_mut().poll_next_back(cx)) {
+                Step::Ready(_) if len > *this.n => {}
+                Step::Ready(item) => {
+                    *this.n -= 1;
+                    return Poll::Ready(Step::Ready(item));
+                }
+                next => return Poll::Ready(next),
+            }
+        }
     }
 }
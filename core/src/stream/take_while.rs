@@ -15,6 +15,7 @@ pin_project! {
         #[pin]
         pub(super) stream: S,
         pub(super) predicate: P,
+        pub(super) done: bool,
     }
 }
 
@@ -25,15 +26,21 @@ where
 {
     type Item = S::Item;
 
+    const SYNC: bool = S::SYNC;
+
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
         let this = self.project();
 
+        if *this.done {
+            return Poll::Ready(Step::Done);
+        }
         Poll::Ready(match ready!(this.stream.poll_next(cx)) {
             Step::NotYet => Step::NotYet,
             Step::Ready(item) => {
                 if (this.predicate)(&item) {
                     Step::Ready(item)
                 } else {
+                    *this.done = true;
                     Step::Done
                 }
             }
@@ -42,6 +49,10 @@ where
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.stream.size_hint()
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, self.stream.size_hint().1)
+        }
     }
 }
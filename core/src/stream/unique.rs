@@ -0,0 +1,79 @@
+use core::{
+    hash::Hash,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::ready;
+use hashbrown::HashSet;
+use pin_project_lite::pin_project;
+
+use super::Stream;
+use crate::step::Step;
+
+/// The stream returned by [`unique()`][`super::ext::StreamExt::unique()`].
+pub type Unique<S> =
+    UniqueBy<S, fn(&<S as Stream>::Item) -> <S as Stream>::Item, <S as Stream>::Item>;
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct UniqueBy<S, F, K> {
+        #[pin]
+        pub(super) stream: S,
+        pub(super) key_fn: F,
+        pub(super) seen: HashSet<K>,
+    }
+}
+
+impl<S, F, K> Stream for UniqueBy<S, F, K>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> K,
+    K: Eq + Hash,
+{
+    type Item = S::Item;
+
+    const SYNC: bool = S::SYNC;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let this = self.project();
+        Poll::Ready(match ready!(this.stream.poll_next(cx)) {
+            Step::NotYet => Step::NotYet,
+            Step::Ready(item) => {
+                if this.seen.insert((this.key_fn)(&item)) {
+                    Step::Ready(item)
+                } else {
+                    Step::NotYet
+                }
+            }
+            Step::Done => Step::Done,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.stream.size_hint();
+        ((self.seen.is_empty() && lower > 0) as usize, upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::stream::{ext::StreamExt, tests::drain, IntoFusion, Stream};
+
+    #[test]
+    fn unique() {
+        let items = [1, 1, 2, 3, 3, 3, 1, 4, 4];
+        assert_eq!(
+            drain(items.into_iter().into_fusion().unique()),
+            [1, 2, 3, 4]
+        );
+        assert_eq!(
+            drain(items.into_iter().into_fusion().unique_by(|i| i % 3)),
+            [1, 2, 3]
+        );
+        let (lower, upper) = items.into_iter().into_fusion().unique().size_hint();
+        assert_eq!((lower, upper), (1, Some(items.len())));
+    }
+}
@@ -0,0 +1,146 @@
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::ready;
+use pin_project_lite::pin_project;
+
+use super::{fuse::Fuse, Stream};
+use crate::step::Step;
+
+/// An item of an event time stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Event<T> {
+    Item(T),
+    /// Promises that no item with an event time before the given one follows.
+    Watermark(u64),
+}
+
+impl<T> Event<T> {
+    #[inline]
+    pub fn item(self) -> Option<T> {
+        match self {
+            Event::Item(item) => Some(item),
+            Event::Watermark(_) => None,
+        }
+    }
+
+    #[inline]
+    pub fn watermark(&self) -> Option<u64> {
+        match self {
+            Event::Item(_) => None,
+            Event::Watermark(watermark) => Some(*watermark),
+        }
+    }
+
+    #[inline]
+    pub fn map<U, F>(self, f: F) -> Event<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Event::Item(item) => Event::Item(f(item)),
+            Event::Watermark(watermark) => Event::Watermark(watermark),
+        }
+    }
+}
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct WithWatermarks<S, F> {
+        #[pin]
+        pub(super) stream: Fuse<S>,
+        pub(super) ts_fn: F,
+        pub(super) max_out_of_orderness: u64,
+        pub(super) watermark: Option<u64>,
+        pub(super) pending: bool,
+    }
+}
+
+impl<S, F> Stream for WithWatermarks<S, F>
+where
+    S: Stream,
+    F: FnMut(&S::Item) -> u64,
+{
+    type Item = Event<S::Item>;
+
+    const SYNC: bool = S::SYNC;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let this = self.project();
+
+        if *this.pending {
+            *this.pending = false;
+            return Poll::Ready(Step::Ready(Event::Watermark(this.watermark.unwrap())));
+        }
+        if this.stream.done {
+            return Poll::Ready(Step::Done);
+        }
+        Poll::Ready(match ready!(this.stream.poll_next(cx)) {
+            Step::Ready(item) => {
+                let watermark = (this.ts_fn)(&item).saturating_sub(*this.max_out_of_orderness);
+                if this.watermark.is_none_or(|current| current < watermark) {
+                    *this.watermark = Some(watermark);
+                    *this.pending = true;
+                }
+                Step::Ready(Event::Item(item))
+            }
+            Step::NotYet => Step::NotYet,
+            // Nothing follows, so every window can be closed.
+            Step::Done if *this.watermark != Some(u64::MAX) => {
+                *this.watermark = Some(u64::MAX);
+                Step::Ready(Event::Watermark(u64::MAX))
+            }
+            Step::Done => Step::Done,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.pending as usize;
+        if self.stream.done {
+            return (pending, Some(pending));
+        }
+        // Every item may advance the watermark, and the last one follows the end of the stream.
+        let last = (self.watermark != Some(u64::MAX)) as usize;
+        let (lower, upper) = self.stream.size_hint();
+        (
+            lower.saturating_add(pending).saturating_add(last),
+            upper
+                .and_then(|upper| upper.checked_mul(2))
+                .and_then(|upper| upper.checked_add(pending + last)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::stream::{ext::StreamExt, tests::drain, IntoFusion, Stream};
+
+    #[test]
+    fn watermarks() {
+        use crate::stream::watermark::Event::{Item, Watermark};
+
+        let events = [1, 3, 2, 8, 7]
+            .into_iter()
+            .into_fusion()
+            .with_watermarks(2, |t| *t);
+        assert_eq!(events.size_hint(), (6, Some(11)));
+        assert_eq!(
+            drain(events),
+            [
+                Item(1),
+                Watermark(0),
+                Item(3),
+                Watermark(1),
+                Item(2),
+                Item(8),
+                Watermark(6),
+                Item(7),
+                Watermark(u64::MAX)
+            ]
+        );
+    }
+}
@@ -0,0 +1,533 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::ready;
+use pin_project_lite::pin_project;
+
+use super::{fuse::Fuse, watermark::Event, Stream};
+use crate::step::Step;
+
+/// A completed window of items, covering event times in `start..end`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Window<T> {
+    pub start: u64,
+    pub end: u64,
+    pub items: Vec<T>,
+}
+
+/// How a window adapter reads its input.
+pub trait Mode<I> {
+    /// The items assigned to windows.
+    type Item;
+
+    /// Whether the watermark follows [`Event::Watermark`]s rather than the timestamps of items.
+    const EVENTS: bool;
+
+    fn into_event(input: I) -> Event<Self::Item>;
+}
+
+/// Windows are closed by the largest timestamp seen so far.
+#[derive(Debug)]
+pub enum Items {}
+
+impl<T> Mode<T> for Items {
+    type Item = T;
+
+    const EVENTS: bool = false;
+
+    #[inline]
+    fn into_event(input: T) -> Event<T> {
+        Event::Item(input)
+    }
+}
+
+/// Windows are closed by the watermarks of an [`Event`] stream, see
+/// [`with_watermarks()`][`super::ext::StreamExt::with_watermarks()`].
+#[derive(Debug)]
+pub enum Events {}
+
+impl<T> Mode<Event<T>> for Events {
+    type Item = T;
+
+    const EVENTS: bool = true;
+
+    #[inline]
+    fn into_event(input: Event<T>) -> Event<T> {
+        input
+    }
+}
+
+/// Open windows by start, along with the event time watermark that closes them.
+///
+/// Open windows either all have the same size or never overlap, so ordering them by start also
+/// orders them by end.
+pub(super) struct Windows<T> {
+    open: BTreeMap<u64, (u64, Vec<T>)>,
+    /// The largest timestamp or watermark seen.
+    progress: Option<u64>,
+    lateness: u64,
+}
+
+impl<T> Windows<T> {
+    pub(super) fn new() -> Self {
+        Windows {
+            open: BTreeMap::new(),
+            progress: None,
+            lateness: 0,
+        }
+    }
+
+    /// Windows ending at or before the watermark are complete.
+    fn watermark(&self) -> Option<u64> {
+        self.progress.map(|ts| ts.saturating_sub(self.lateness))
+    }
+
+    fn pop_complete(&mut self, flush: bool) -> Option<Window<T>> {
+        let watermark = self.watermark();
+        let entry = self.open.first_entry()?;
+        let (end, _) = *entry.get();
+        if !flush && !is_late(watermark, end) {
+            return None;
+        }
+        let (start, (end, items)) = entry.remove_entry();
+        Some(Window { start, end, items })
+    }
+
+    fn size_hint(
+        &self,
+        done: bool,
+        (_, upper): (usize, Option<usize>),
+        per_item: usize,
+    ) -> (usize, Option<usize>) {
+        let open = self.open.len();
+        if done {
+            return (open, Some(open));
+        }
+        (
+            0,
+            upper
+                .and_then(|upper| upper.checked_mul(per_item))
+                .and_then(|upper| upper.checked_add(open)),
+        )
+    }
+}
+
+/// Whether a window ending at `end` has been closed by `watermark`.
+fn is_late(watermark: Option<u64>, end: u64) -> bool {
+    watermark.is_some_and(|watermark| end <= watermark)
+}
+
+/// Drives `stream` item by item into `windows`, yielding windows as the watermark passes their
+/// end and flushing all open windows once `stream` is done.
+fn poll_windows<S, M, F>(
+    mut stream: Pin<&mut Fuse<S>>,
+    windows: &mut Windows<M::Item>,
+    ts_fn: &mut F,
+    cx: &mut Context<'_>,
+    assign: impl FnOnce(&mut Windows<M::Item>, Option<u64>, u64, M::Item),
+) -> Poll<Step<Window<M::Item>>>
+where
+    S: Stream,
+    M: Mode<S::Item>,
+    F: FnMut(&M::Item) -> u64,
+{
+    if let Some(window) = windows.pop_complete(stream.done) {
+        return Poll::Ready(Step::Ready(window));
+    }
+    if stream.done {
+        return Poll::Ready(Step::Done);
+    }
+    match ready!(stream.as_mut().poll_next(cx)) {
+        Step::Ready(input) => {
+            match M::into_event(input) {
+                Event::Item(item) => {
+                    let ts = ts_fn(&item);
+                    let watermark = windows.watermark();
+                    assign(windows, watermark, ts, item);
+                    if !M::EVENTS {
+                        windows.progress = windows.progress.max(Some(ts));
+                    }
+                }
+                Event::Watermark(watermark) => {
+                    windows.progress = windows.progress.max(Some(watermark));
+                }
+            }
+            Poll::Ready(
+                windows
+                    .pop_complete(false)
+                    .map_or(Step::NotYet, Step::Ready),
+            )
+        }
+        Step::NotYet => Poll::Ready(Step::NotYet),
+        Step::Done => Poll::Ready(windows.pop_complete(true).into()),
+    }
+}
+
+macro_rules! allowed_lateness {
+    ($window:ident) => {
+        impl<S: Stream, F, M: Mode<S::Item>> $window<S, F, M> {
+            /// Keeps windows open until the watermark is `lateness` past their end, so that items
+            /// arriving out of order still make it into them.
+            ///
+            /// Items whose windows have all been emitted are dropped. Defaults to `0`.
+            pub fn allowed_lateness(mut self, lateness: u64) -> Self {
+                self.windows.lateness = lateness;
+                self
+            }
+        }
+    };
+}
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct TumblingWindow<S: Stream, F, M: Mode<S::Item> = Items> {
+        #[pin]
+        pub(super) stream: Fuse<S>,
+        pub(super) ts_fn: F,
+        pub(super) size: u64,
+        pub(super) windows: Windows<M::Item>,
+    }
+}
+
+allowed_lateness!(TumblingWindow);
+
+impl<S, F, M> Stream for TumblingWindow<S, F, M>
+where
+    S: Stream,
+    M: Mode<S::Item>,
+    F: FnMut(&M::Item) -> u64,
+{
+    type Item = Window<M::Item>;
+
+    const SYNC: bool = S::SYNC;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let this = self.project();
+        let size = *this.size;
+        poll_windows::<_, M, _>(
+            this.stream,
+            this.windows,
+            this.ts_fn,
+            cx,
+            |windows, watermark, ts, item| {
+                let start = ts - ts % size;
+                let end = start.saturating_add(size);
+                if !is_late(watermark, end) {
+                    windows
+                        .open
+                        .entry(start)
+                        .or_insert((end, Vec::new()))
+                        .1
+                        .push(item);
+                }
+            },
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.windows
+            .size_hint(self.stream.done, self.stream.size_hint(), 1)
+    }
+}
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct SlidingWindow<S: Stream, F, M: Mode<S::Item> = Items> {
+        #[pin]
+        pub(super) stream: Fuse<S>,
+        pub(super) ts_fn: F,
+        pub(super) size: u64,
+        pub(super) slide: u64,
+        pub(super) windows: Windows<M::Item>,
+    }
+}
+
+allowed_lateness!(SlidingWindow);
+
+impl<S, F, M> Stream for SlidingWindow<S, F, M>
+where
+    S: Stream,
+    M: Mode<S::Item>,
+    M::Item: Clone,
+    F: FnMut(&M::Item) -> u64,
+{
+    type Item = Window<M::Item>;
+
+    const SYNC: bool = S::SYNC;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let this = self.project();
+        let (size, slide) = (*this.size, *this.slide);
+        poll_windows::<_, M, _>(
+            this.stream,
+            this.windows,
+            this.ts_fn,
+            cx,
+            |windows, watermark, ts, item| {
+                // Windows start at multiples of `slide` and span `size`. Starts past `u64::MAX`
+                // can't be represented, and so neither can the windows of `ts`.
+                let first = ts.saturating_add(1).saturating_sub(size).div_ceil(slide);
+                let Some(first) = first.checked_mul(slide) else {
+                    return;
+                };
+                let mut starts = (first..=ts)
+                    .step_by(usize::try_from(slide).unwrap_or(usize::MAX))
+                    .filter(|start| !is_late(watermark, start.saturating_add(size)))
+                    .peekable();
+                let mut item = Some(item);
+                while let Some(start) = starts.next() {
+                    let item = match starts.peek() {
+                        Some(_) => item.clone(),
+                        None => item.take(),
+                    };
+                    let end = start.saturating_add(size);
+                    windows
+                        .open
+                        .entry(start)
+                        .or_insert((end, Vec::new()))
+                        .1
+                        .extend(item);
+                }
+            },
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let per_item = self.size.div_ceil(self.slide);
+        self.windows.size_hint(
+            self.stream.done,
+            self.stream.size_hint(),
+            usize::try_from(per_item).unwrap_or(usize::MAX),
+        )
+    }
+}
+
+pin_project! {
+    #[must_use = "streams do nothing unless polled"]
+    pub struct SessionWindow<S: Stream, F, M: Mode<S::Item> = Items> {
+        #[pin]
+        pub(super) stream: Fuse<S>,
+        pub(super) ts_fn: F,
+        pub(super) gap: u64,
+        pub(super) windows: Windows<M::Item>,
+    }
+}
+
+allowed_lateness!(SessionWindow);
+
+impl<S, F, M> Stream for SessionWindow<S, F, M>
+where
+    S: Stream,
+    M: Mode<S::Item>,
+    F: FnMut(&M::Item) -> u64,
+{
+    type Item = Window<M::Item>;
+
+    const SYNC: bool = S::SYNC;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Step<Self::Item>> {
+        let this = self.project();
+        let gap = *this.gap;
+        poll_windows::<_, M, _>(
+            this.stream,
+            this.windows,
+            this.ts_fn,
+            cx,
+            |windows, watermark, ts, item| {
+                let (mut start, mut end) = (ts, ts.saturating_add(gap));
+                if is_late(watermark, end) {
+                    return;
+                }
+                // Sessions overlapping `ts..ts + gap` merge with it. Open sessions are disjoint, so
+                // those are the last ones starting before its end.
+                let overlapping: Vec<_> = windows
+                    .open
+                    .range(..end)
+                    .rev()
+                    .take_while(|(_, (session_end, _))| *session_end > ts)
+                    .map(|(session_start, _)| *session_start)
+                    .collect();
+                let mut items = Vec::new();
+                for session_start in overlapping.into_iter().rev() {
+                    let (session_end, session_items) = windows.open.remove(&session_start).unwrap();
+                    start = start.min(session_start);
+                    end = end.max(session_end);
+                    if items.is_empty() {
+                        items = session_items;
+                    } else {
+                        items.extend(session_items);
+                    }
+                }
+                items.push(item);
+                windows.open.insert(start, (end, items));
+            },
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.windows
+            .size_hint(self.stream.done, self.stream.size_hint(), 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use crate::stream::{ext::StreamExt, tests::drain, IntoFusion};
+
+    #[test]
+    fn window() {
+        use crate::stream::window::Window;
+
+        let window = |start, end, items: &[u64]| Window {
+            start,
+            end,
+            items: items.to_vec(),
+        };
+        let events = [1, 3, 4, 11, 9, 12, 25, 14, 27];
+
+        assert_eq!(
+            drain(events.into_iter().into_fusion().window_tumbling(10, |t| *t)),
+            [
+                window(0, 10, &[1, 3, 4]),
+                window(10, 20, &[11, 12]),
+                window(20, 30, &[25, 27])
+            ]
+        );
+        // Lateness keeps windows open for the out of order 9 and 14.
+        assert_eq!(
+            drain(
+                events
+                    .into_iter()
+                    .into_fusion()
+                    .filter(|_| true)
+                    .window_tumbling(10, |t| *t)
+                    .allowed_lateness(6)
+            ),
+            [
+                window(0, 10, &[1, 3, 4, 9]),
+                window(10, 20, &[11, 12, 14]),
+                window(20, 30, &[25, 27])
+            ]
+        );
+
+        assert_eq!(
+            drain(
+                [1, 6, 12]
+                    .into_iter()
+                    .into_fusion()
+                    .window_sliding(10, 5, |t| *t)
+            ),
+            [
+                window(0, 10, &[1, 6]),
+                window(5, 15, &[6, 12]),
+                window(10, 20, &[12])
+            ]
+        );
+        // The next window start after `1 << 63` is past `u64::MAX`.
+        assert_eq!(
+            drain(
+                [0, 1 << 63, u64::MAX - 1]
+                    .into_iter()
+                    .into_fusion()
+                    .window_sliding(1, 1 << 63, |t| *t)
+            ),
+            [
+                window(0, 1, &[0]),
+                window(1 << 63, (1 << 63) + 1, &[1 << 63])
+            ]
+        );
+
+        assert_eq!(
+            drain(events.into_iter().into_fusion().window_session(3, |t| *t)),
+            [
+                window(1, 7, &[1, 3, 4]),
+                window(9, 15, &[11, 9, 12]),
+                window(25, 30, &[25, 27])
+            ]
+        );
+        // A late item bridges two open sessions.
+        assert_eq!(
+            drain(
+                [1, 10, 5, 30]
+                    .into_iter()
+                    .into_fusion()
+                    .window_session(6, |t| *t)
+                    .allowed_lateness(20)
+            ),
+            [window(1, 16, &[1, 10, 5]), window(30, 36, &[30])]
+        );
+    }
+
+    #[test]
+    fn event_window() {
+        use crate::stream::{source, window::Window};
+
+        let window = |start, end, items: &[u64]| Window {
+            start,
+            end,
+            items: items.to_vec(),
+        };
+
+        // Watermarks close windows while the stream is still pending. The 4 is within the bound
+        // and makes it into its window, while the 9 is behind the watermark.
+        assert_eq!(
+            drain(
+                [1, 3, 12, 4, 25, 9]
+                    .into_iter()
+                    .into_fusion()
+                    .with_watermarks(5, |t| *t)
+                    .chain(source::pending())
+                    .event_window_tumbling(10, |t| *t)
+                    .take(2)
+            ),
+            [window(0, 10, &[1, 3, 4]), window(10, 20, &[12])]
+        );
+        assert_eq!(
+            drain(
+                [1, 3, 12, 4, 25, 9]
+                    .into_iter()
+                    .into_fusion()
+                    .with_watermarks(5, |t| *t)
+                    .event_window_tumbling(10, |t| *t)
+            ),
+            [
+                window(0, 10, &[1, 3, 4]),
+                window(10, 20, &[12]),
+                window(20, 30, &[25])
+            ]
+        );
+
+        assert_eq!(
+            drain(
+                [1, 6, 12]
+                    .into_iter()
+                    .into_fusion()
+                    .with_watermarks(0, |t| *t)
+                    .event_window_sliding(10, 5, |t| *t)
+            ),
+            [
+                window(0, 10, &[1, 6]),
+                window(5, 15, &[6, 12]),
+                window(10, 20, &[12])
+            ]
+        );
+
+        assert_eq!(
+            drain(
+                [1, 2, 10]
+                    .into_iter()
+                    .into_fusion()
+                    .with_watermarks(0, |t| *t)
+                    .chain(source::pending())
+                    .event_window_session(3, |t| *t)
+                    .take(1)
+            ),
+            [window(1, 5, &[1, 2])]
+        );
+    }
+}
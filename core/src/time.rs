@@ -0,0 +1,84 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::{
+    cell::{Cell, RefCell},
+    task::Waker,
+};
+use core::{task::Context, time::Duration};
+
+/// A clock that can wake tasks, supplied by the runtime a pipeline runs on.
+pub trait Timer {
+    /// The time elapsed since an arbitrary, fixed origin. It must never go backwards.
+    fn now(&self) -> Duration;
+
+    /// Wakes the task of `cx` once [`now()`][`Timer::now()`] reaches `deadline`, or right away if
+    /// it already has.
+    fn wake_at(&self, deadline: Duration, cx: &mut Context<'_>);
+}
+
+impl<T: Timer + ?Sized> Timer for &T {
+    #[inline]
+    fn now(&self) -> Duration {
+        (**self).now()
+    }
+
+    #[inline]
+    fn wake_at(&self, deadline: Duration, cx: &mut Context<'_>) {
+        (**self).wake_at(deadline, cx)
+    }
+}
+
+/// A [`Timer`] whose time only moves when [`advance()`][`ManualTimer::advance()`] is called, for
+/// tests and simulations.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Default)]
+pub struct ManualTimer {
+    now: Cell<Duration>,
+    wakers: RefCell<Vec<(Duration, Waker)>>,
+}
+
+#[cfg(feature = "alloc")]
+impl ManualTimer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Moves the time forward by `duration`, waking every task whose deadline has been reached.
+    pub fn advance(&self, duration: Duration) {
+        let now = self.now.get() + duration;
+        self.now.set(now);
+        // Wake outside of the borrow, in case a waker polls and registers again.
+        let mut due = Vec::new();
+        self.wakers.borrow_mut().retain(|(deadline, waker)| {
+            if *deadline <= now {
+                due.push(waker.clone());
+            }
+            *deadline > now
+        });
+        due.into_iter().for_each(Waker::wake);
+    }
+
+    /// The number of tasks waiting to be woken.
+    pub fn waiting(&self) -> usize {
+        self.wakers.borrow().len()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Timer for ManualTimer {
+    #[inline]
+    fn now(&self) -> Duration {
+        self.now.get()
+    }
+
+    fn wake_at(&self, deadline: Duration, cx: &mut Context<'_>) {
+        if deadline <= self.now.get() {
+            cx.waker().wake_by_ref();
+        } else {
+            self.wakers
+                .borrow_mut()
+                .push((deadline, cx.waker().clone()));
+        }
+    }
+}
@@ -27,12 +27,12 @@ impl<'slice, T> Iterator for Split<'slice, T> {
         }
         let s = &self.slice[self.n..end];
         self.n = end;
-        Some(s.into_iter().into())
+        Some(s.iter().into())
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
         let mut size = self.slice.len() / self.step;
-        if self.slice.len() % self.step > 0 {
+        if !self.slice.len().is_multiple_of(self.step) {
             size += 1;
         }
         (size, Some(size))
@@ -61,7 +61,7 @@ mod tests {
 
     #[test]
     fn test() {
-        let a = vec![1, 2, 3];
+        let a = [1, 2, 3];
         let mut i = a.fusion_split(2);
         loop {
             let item = i.next();